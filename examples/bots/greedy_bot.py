@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+"""Example snake bot: heads for the food, avoiding walls and its own body.
+
+Run it with `snake --bot "python3 examples/bots/greedy_bot.py"`. Every tick
+the game writes the state as one line of JSON to standard input and expects
+a direction (`up`, `down`, `left` or `right`) on standard output, after the
+tick it answers so that late answers are not mistaken for this tick's.
+"""
+
+import json
+import sys
+
+MOVES = {"Up": (0, -1), "Down": (0, 1), "Left": (-1, 0), "Right": (1, 0)}
+OPPOSITE = {"Up": "Down", "Down": "Up", "Left": "Right", "Right": "Left"}
+
+
+def next_cell(state, x, y):
+    board = state["board"]
+    if board["topology"] == "Wrapping":
+        x = 1 + (x - 1) % (board["width"] - 2)
+        y = 1 + (y - 1) % (board["height"] - 2)
+    return x, y
+
+
+def is_safe(state, x, y):
+    board = state["board"]
+    if x <= 0 or y <= 0 or x >= board["width"] - 1 or y >= board["height"] - 1:
+        return False
+    # The tail moves out of the way on this tick
+    return [x, y] not in state["snake"][:-1]
+
+
+def choose(state):
+    head_x, head_y = state["snake"][0]
+    food_x, food_y = state["food"]
+    best, best_distance = state["direction"], None
+
+    for name, (dx, dy) in MOVES.items():
+        if name == OPPOSITE[state["direction"]]:
+            continue
+        x, y = next_cell(state, head_x + dx, head_y + dy)
+        if not is_safe(state, x, y):
+            continue
+        distance = abs(x - food_x) + abs(y - food_y)
+        if best_distance is None or distance < best_distance:
+            best, best_distance = name, distance
+
+    return best
+
+
+for line in sys.stdin:
+    state = json.loads(line)
+    print(state["tick"], choose(state).lower(), flush=True)
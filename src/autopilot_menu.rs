@@ -0,0 +1,98 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+pub struct AutopilotMenu {
+    items: Vec<(String, Option<String>)>, // Label and strategy name, `None` to play yourself
+    state: ListState,
+}
+
+impl AutopilotMenu {
+    /// Offers every strategy in `names` besides playing yourself.
+    pub fn new<'a, I: IntoIterator<Item = &'a str>>(names: I) -> Self {
+        let mut items = vec![("Off (play yourself)".to_string(), None)];
+        items.extend(
+            names
+                .into_iter()
+                .map(|name| (format!("Autopilot: {}", name), Some(name.to_string()))),
+        );
+
+        Self {
+            items,
+            state: ListState::default(),
+        }
+    }
+
+    pub fn next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            "Select Autopilot",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|(label, _)| ListItem::new(Spans::from(label.as_str())))
+            .collect();
+
+        let menu = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().bg(Color::Yellow).fg(Color::Black))
+            .highlight_symbol("> ");
+
+        f.render_stateful_widget(menu, chunks[1], &mut self.state);
+    }
+
+    /// The selected strategy name, `Some(None)` when autopilot was turned off.
+    pub fn get_selected_strategy(&self) -> Option<Option<String>> {
+        self.state.selected().map(|i| self.items[i].1.clone())
+    }
+}
@@ -0,0 +1,264 @@
+use serde::Serialize;
+use std::{
+    io::{self, Write},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    board::BoardConfig,
+    simulation::{DeathCause, Simulation, StepOutcome},
+    strategy::{Strategy, StrategyRegistry},
+};
+
+/// How a headless benchmark game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GameOutcome {
+    Died(DeathCause),
+    /// The snake filled the whole board.
+    Won,
+    /// The game was still running after the maximum number of ticks, e.g.
+    /// because the strategy circles forever without reaching the food.
+    TimedOut,
+}
+
+/// The result of one headless game.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameResult {
+    pub seed: u64,
+    pub score: u32,
+    pub ticks: u64,
+    pub outcome: GameOutcome,
+}
+
+/// Lets `strategy` play a game on `board` without a terminal, for at most
+/// `max_ticks` ticks.
+pub fn play_game(
+    strategy: &mut dyn Strategy,
+    board: BoardConfig,
+    seed: u64,
+    max_ticks: u64,
+) -> GameResult {
+    let mut simulation = Simulation::new(board, seed);
+
+    let outcome = loop {
+        if simulation.ticks() >= max_ticks {
+            break GameOutcome::TimedOut;
+        }
+
+        match simulation.step(Some(strategy.next_direction(&simulation.view()))) {
+            StepOutcome::Moved | StepOutcome::Ate => {}
+            StepOutcome::Died(cause) => break GameOutcome::Died(cause),
+            StepOutcome::Won => break GameOutcome::Won,
+        }
+    };
+
+    GameResult {
+        seed,
+        score: simulation.score(),
+        ticks: simulation.ticks(),
+        outcome,
+    }
+}
+
+/// Statistics over every game one strategy played on one board.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchSummary {
+    pub strategy: String,
+    pub board: BoardConfig,
+    pub games: usize,
+    pub average_score: f64,
+    pub median_score: f64,
+    pub max_score: u32,
+    /// Average game length in ticks.
+    pub average_ticks: f64,
+    pub wins: usize,
+    pub wall_deaths: usize,
+    pub self_collisions: usize,
+    pub timeouts: usize,
+    pub games_per_second: f64,
+}
+
+impl BenchSummary {
+    /// Sums up `results`, which took `elapsed` to play.
+    pub fn new(
+        strategy: &str,
+        board: BoardConfig,
+        results: &[GameResult],
+        elapsed: Duration,
+    ) -> Self {
+        let games = results.len();
+        let count = |outcome: GameOutcome| {
+            results
+                .iter()
+                .filter(|result| result.outcome == outcome)
+                .count()
+        };
+
+        let mut scores: Vec<u32> = results.iter().map(|result| result.score).collect();
+        scores.sort_unstable();
+        let median_score = match games {
+            0 => 0.0,
+            _ if games.is_multiple_of(2) => {
+                (scores[games / 2 - 1] + scores[games / 2]) as f64 / 2.0
+            }
+            _ => scores[games / 2] as f64,
+        };
+
+        Self {
+            strategy: strategy.to_string(),
+            board,
+            games,
+            average_score: average(scores.iter().map(|&score| score as f64), games),
+            median_score,
+            max_score: scores.last().copied().unwrap_or_default(),
+            average_ticks: average(results.iter().map(|result| result.ticks as f64), games),
+            wins: count(GameOutcome::Won),
+            wall_deaths: count(GameOutcome::Died(DeathCause::Wall)),
+            self_collisions: count(GameOutcome::Died(DeathCause::SelfCollision)),
+            timeouts: count(GameOutcome::TimedOut),
+            games_per_second: if elapsed.is_zero() {
+                0.0
+            } else {
+                games as f64 / elapsed.as_secs_f64()
+            },
+        }
+    }
+}
+
+fn average<I: Iterator<Item = f64>>(values: I, count: usize) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        values.sum::<f64>() / count as f64
+    }
+}
+
+/// Plays one game per seed with a fresh instance of the strategy registered
+/// under `name`. Returns `None` when no such strategy is registered.
+pub fn run_strategy(
+    registry: &StrategyRegistry,
+    name: &str,
+    board: BoardConfig,
+    seeds: impl Iterator<Item = u64>,
+    max_ticks: u64,
+) -> Option<BenchSummary> {
+    let started = Instant::now();
+    let mut results = Vec::new();
+
+    for seed in seeds {
+        let mut strategy = registry.create(name)?;
+        results.push(play_game(strategy.as_mut(), board, seed, max_ticks));
+    }
+
+    Some(BenchSummary::new(name, board, &results, started.elapsed()))
+}
+
+/// How benchmark results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Aligned columns for reading in the terminal.
+    #[default]
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("unknown output format '{}'", value)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "strategy,width,height,mode,games,average_score,median_score,max_score,average_ticks,wins,wall_deaths,self_collisions,timeouts,games_per_second";
+
+/// Writes `summaries` to `writer` in the given format.
+pub fn write_report<W: Write>(
+    summaries: &[BenchSummary],
+    format: OutputFormat,
+    mut writer: W,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Table => {
+            writeln!(
+                writer,
+                "{:<14} {:<18} {:>6} {:>9} {:>7} {:>6} {:>10} {:>5} {:>5} {:>5} {:>8} {:>9}",
+                "strategy",
+                "board",
+                "games",
+                "avg score",
+                "median",
+                "max",
+                "avg ticks",
+                "won",
+                "wall",
+                "self",
+                "timeout",
+                "games/s"
+            )?;
+            for summary in summaries {
+                writeln!(
+                    writer,
+                    "{:<14} {:<18} {:>6} {:>9.1} {:>7.1} {:>6} {:>10.1} {:>5} {:>5} {:>5} {:>8} {:>9.1}",
+                    summary.strategy,
+                    format!("{} {}", summary.board, summary.board.topology.name()),
+                    summary.games,
+                    summary.average_score,
+                    summary.median_score,
+                    summary.max_score,
+                    summary.average_ticks,
+                    summary.wins,
+                    summary.wall_deaths,
+                    summary.self_collisions,
+                    summary.timeouts,
+                    summary.games_per_second
+                )?;
+            }
+        }
+        OutputFormat::Csv => {
+            writeln!(writer, "{}", CSV_HEADER)?;
+            for summary in summaries {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{:.3},{:.1},{},{:.3},{},{},{},{},{:.3}",
+                    csv_field(&summary.strategy),
+                    summary.board.width,
+                    summary.board.height,
+                    csv_field(summary.board.topology.name()),
+                    summary.games,
+                    summary.average_score,
+                    summary.median_score,
+                    summary.max_score,
+                    summary.average_ticks,
+                    summary.wins,
+                    summary.wall_deaths,
+                    summary.self_collisions,
+                    summary.timeouts,
+                    summary.games_per_second
+                )?;
+            }
+        }
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut writer, summaries)?;
+            writeln!(writer)?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// Quotes a CSV field when it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
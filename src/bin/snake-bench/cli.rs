@@ -0,0 +1,110 @@
+use std::{ops::Range, time::Duration};
+
+use snake::{
+    bench::OutputFormat,
+    board::BoardConfig,
+    board::Topology,
+    cli::{CliError, DEFAULT_BOT_TIMEOUT},
+};
+
+/// Options passed to the `snake-bench` binary on the command line.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of games played by every strategy on every board.
+    pub games: u64,
+    /// Seed of the first game, the following games use the next seeds.
+    pub seed: u64,
+    /// Strategies to compare. Every registered strategy when empty.
+    pub strategies: Vec<String>,
+    pub boards: Vec<BoardConfig>,
+    /// Games still running after this many ticks count as timed out.
+    pub max_ticks: u64,
+    pub format: OutputFormat,
+    /// Command starting an external bot to compare with the built-in strategies.
+    pub bot: Option<String>,
+    /// How long the bot may think each tick before the snake keeps going.
+    pub bot_timeout: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            games: 20,
+            seed: 0,
+            strategies: Vec::new(),
+            boards: Vec::new(),
+            max_ticks: 100_000,
+            format: OutputFormat::default(),
+            bot: None,
+            bot_timeout: DEFAULT_BOT_TIMEOUT,
+        }
+    }
+}
+
+pub const USAGE: &str = "Usage: snake-bench [--games <number>] [--seed <number>] \
+[--strategy <name>]... [--board <width>x<height>]... [--wrap] [--max-ticks <number>] \
+[--format table|csv|json] [--bot <command>] [--bot-timeout <milliseconds>]";
+
+impl Options {
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self, CliError> {
+        let mut options = Options::default();
+        let mut topology = Topology::Walled;
+
+        while let Some(argument) = args.next() {
+            match argument.as_str() {
+                "--games" => options.games = parse_value(&mut args, "--games")?,
+                "--seed" => options.seed = parse_value(&mut args, "--seed")?,
+                "--strategy" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--strategy"))?;
+                    options.strategies.push(value);
+                }
+                "--board" => options.boards.push(parse_value(&mut args, "--board")?),
+                "--wrap" => topology = Topology::Wrapping,
+                "--max-ticks" => options.max_ticks = parse_value(&mut args, "--max-ticks")?,
+                "--format" => options.format = parse_value(&mut args, "--format")?,
+                "--bot" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--bot"))?;
+                    options.bot = Some(value);
+                }
+                "--bot-timeout" => {
+                    options.bot_timeout =
+                        Duration::from_millis(parse_value(&mut args, "--bot-timeout")?);
+                }
+                _ => return Err(CliError::UnknownArgument(argument)),
+            }
+        }
+
+        if options.seed.checked_add(options.games).is_none() {
+            return Err(CliError::Conflict(format!(
+                "{} games from seed {} run past the largest seed",
+                options.games, options.seed
+            )));
+        }
+
+        if options.boards.is_empty() {
+            options.boards.push(BoardConfig::default());
+        }
+        for board in &mut options.boards {
+            *board = board.with_topology(topology);
+        }
+
+        Ok(options)
+    }
+
+    /// The seed of every game, one after the other.
+    pub fn seeds(&self) -> Range<u64> {
+        // `parse` made sure the range does not overflow
+        self.seed..self.seed.saturating_add(self.games)
+    }
+}
+
+fn parse_value<T, I>(args: &mut I, flag: &'static str) -> Result<T, CliError>
+where
+    T: std::str::FromStr,
+    I: Iterator<Item = String>,
+{
+    let value = args.next().ok_or(CliError::MissingValue(flag))?;
+    value
+        .parse()
+        .map_err(|_| CliError::InvalidValue(flag, value))
+}
@@ -0,0 +1,58 @@
+mod cli;
+
+use cli::Options;
+use snake::{
+    bench::{self, BenchSummary},
+    strategy::{ProcessStrategy, StrategyRegistry},
+};
+use std::{error::Error, io};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let mut registry = StrategyRegistry::with_builtin();
+    if let Some(command) = options.bot.clone() {
+        if let Err(error) = ProcessStrategy::check_command_line(&command) {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+
+        let timeout = options.bot_timeout;
+        registry.register("Bot", move || {
+            Box::new(ProcessStrategy::from_command_line(&command, timeout))
+        });
+    }
+    let strategies: Vec<String> = if options.strategies.is_empty() {
+        registry.names().map(str::to_string).collect()
+    } else {
+        options.strategies.clone()
+    };
+
+    let mut summaries: Vec<BenchSummary> = Vec::new();
+    for board in &options.boards {
+        for name in &strategies {
+            match bench::run_strategy(&registry, name, *board, options.seeds(), options.max_ticks) {
+                Some(summary) => summaries.push(summary),
+                None => {
+                    let available: Vec<&str> = registry.names().collect();
+                    eprintln!(
+                        "unknown strategy '{}' (available: {})",
+                        name,
+                        available.join(", ")
+                    );
+                    std::process::exit(2);
+                }
+            }
+        }
+    }
+
+    bench::write_report(&summaries, options.format, io::stdout().lock())?;
+
+    Ok(())
+}
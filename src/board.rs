@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+use crate::{
+    constants::{BOARD_HEIGHT, BOARD_WIDTH, MIN_BOARD_HEIGHT, MIN_BOARD_WIDTH},
+    coordinate::Coordinate,
+    direction::Direction,
+};
+
+/// How the edges of the board behave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Topology {
+    /// The border is a wall and running into it ends the game.
+    #[default]
+    Walled,
+    /// The board is a torus: leaving through one edge re-enters on the opposite one.
+    Wrapping,
+}
+
+impl Topology {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Topology::Walled => "Classic",
+            Topology::Wrapping => "Wrap-around",
+        }
+    }
+}
+
+/// Dimensions of the game board, including the one cell wide border around it.
+///
+/// The playable area is everything inside the border, i.e. columns
+/// `1..width - 1` and rows `1..height - 1`. With [`Topology::Wrapping`] the
+/// border is only drawn as a frame and never collided with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardConfig {
+    pub width: i32,
+    pub height: i32,
+    pub topology: Topology,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    /// The requested board is smaller than `MIN_BOARD_WIDTH` x `MIN_BOARD_HEIGHT`.
+    TooSmall { width: i32, height: i32 },
+    /// The board does not fit into a terminal of the given size.
+    TooLarge {
+        width: i32,
+        height: i32,
+        columns: u16,
+        rows: u16,
+    },
+    /// The text could not be parsed as `<width>x<height>`.
+    Malformed(String),
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::TooSmall { width, height } => write!(
+                f,
+                "board {}x{} is smaller than the minimum of {}x{}",
+                width, height, MIN_BOARD_WIDTH, MIN_BOARD_HEIGHT
+            ),
+            BoardError::TooLarge {
+                width,
+                height,
+                columns,
+                rows,
+            } => write!(
+                f,
+                "board {}x{} does not fit in a {}x{} terminal",
+                width, height, columns, rows
+            ),
+            BoardError::Malformed(text) => {
+                write!(f, "'{}' is not a board size like 40x20", text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+impl BoardConfig {
+    pub fn new(width: i32, height: i32) -> Result<Self, BoardError> {
+        if width < MIN_BOARD_WIDTH || height < MIN_BOARD_HEIGHT {
+            return Err(BoardError::TooSmall { width, height });
+        }
+
+        Ok(Self {
+            width,
+            height,
+            topology: Topology::Walled,
+        })
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Self {
+        Self { topology, ..self }
+    }
+
+    pub fn center(&self) -> Coordinate {
+        Coordinate(self.width / 2, self.height / 2)
+    }
+
+    /// Returns `true` for cells on the border or outside of the board.
+    pub fn is_border(&self, coordinate: Coordinate) -> bool {
+        coordinate.0 <= 0
+            || coordinate.0 >= self.width - 1
+            || coordinate.1 <= 0
+            || coordinate.1 >= self.height - 1
+    }
+
+    /// Returns `true` for cells that end the game when entered. Only walled
+    /// boards have walls.
+    pub fn is_wall(&self, coordinate: Coordinate) -> bool {
+        self.topology == Topology::Walled && self.is_border(coordinate)
+    }
+
+    /// Maps a coordinate back into the playable area on wrapping boards.
+    /// Walled boards return the coordinate unchanged.
+    pub fn wrap(&self, coordinate: Coordinate) -> Coordinate {
+        match self.topology {
+            Topology::Walled => coordinate,
+            Topology::Wrapping => Coordinate(
+                1 + (coordinate.0 - 1).rem_euclid(self.width - 2),
+                1 + (coordinate.1 - 1).rem_euclid(self.height - 2),
+            ),
+        }
+    }
+
+    /// The cell reached by moving one step from `coordinate` in `direction`.
+    pub fn next_position(&self, coordinate: Coordinate, direction: Direction) -> Coordinate {
+        let next = match direction {
+            Direction::Up => Coordinate(coordinate.0, coordinate.1 - 1),
+            Direction::Down => Coordinate(coordinate.0, coordinate.1 + 1),
+            Direction::Left => Coordinate(coordinate.0 - 1, coordinate.1),
+            Direction::Right => Coordinate(coordinate.0 + 1, coordinate.1),
+        };
+
+        self.wrap(next)
+    }
+
+    /// Manhattan distance between two cells, taking the shorter way around
+    /// the edges on wrapping boards.
+    pub fn distance(&self, from: Coordinate, to: Coordinate) -> i32 {
+        let dx = (from.0 - to.0).abs();
+        let dy = (from.1 - to.1).abs();
+
+        match self.topology {
+            Topology::Walled => dx + dy,
+            Topology::Wrapping => dx.min(self.width - 2 - dx) + dy.min(self.height - 2 - dy),
+        }
+    }
+
+    /// Number of cells inside the border.
+    pub fn playable_area(&self) -> usize {
+        ((self.width - 2) * (self.height - 2)) as usize
+    }
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        Self {
+            width: BOARD_WIDTH,
+            height: BOARD_HEIGHT,
+            topology: Topology::Walled,
+        }
+    }
+}
+
+impl fmt::Display for BoardConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+impl FromStr for BoardConfig {
+    type Err = BoardError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let malformed = || BoardError::Malformed(text.to_string());
+        let (width, height) = text.split_once(['x', 'X']).ok_or_else(malformed)?;
+        let width = width.trim().parse().map_err(|_| malformed())?;
+        let height = height.trim().parse().map_err(|_| malformed())?;
+
+        Self::new(width, height)
+    }
+}
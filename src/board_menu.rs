@@ -0,0 +1,112 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use snake::{board::BoardConfig, screen::RenderMode};
+
+pub struct BoardMenu {
+    items: Vec<(String, BoardConfig)>, // Board name and dimensions
+    state: ListState,
+}
+
+impl BoardMenu {
+    /// Builds the list of board presets, plus a board filling a terminal of
+    /// the given size in `mode` when that terminal is large enough.
+    pub fn new(columns: u16, rows: u16, mode: RenderMode) -> Self {
+        let presets = [
+            ("Tiny", 10, 8),
+            ("Small", 20, 12),
+            ("Default", 40, 20),
+            ("Large", 60, 30),
+        ];
+
+        let mut items: Vec<(String, BoardConfig)> = presets
+            .iter()
+            .filter_map(|&(name, width, height)| {
+                let board = BoardConfig::new(width, height).ok()?;
+                Some((format!("{} ({})", name, board), board))
+            })
+            .collect();
+
+        if let Ok(board) = mode.fill_terminal(columns, rows) {
+            items.push((format!("Fill terminal ({})", board), board));
+        }
+
+        Self {
+            items,
+            state: ListState::default(),
+        }
+    }
+
+    pub fn next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            "Select Board Size",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|(name, _)| ListItem::new(Spans::from(name.as_str())))
+            .collect();
+
+        let menu = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().bg(Color::Yellow).fg(Color::Black))
+            .highlight_symbol("> ");
+
+        f.render_stateful_widget(menu, chunks[1], &mut self.state);
+    }
+
+    pub fn get_selected_board(&self) -> Option<BoardConfig> {
+        self.state.selected().map(|i| self.items[i].1)
+    }
+}
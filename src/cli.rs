@@ -0,0 +1,155 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    board::BoardConfig,
+    screen::{Glyphs, RenderMode},
+    strategy::protocol::BotMode,
+};
+
+/// Options passed to the `snake` binary on the command line.
+#[derive(Debug)]
+pub struct Options {
+    /// Seed for every game started in this session. A fresh random seed is
+    /// picked for each game when absent.
+    pub seed: Option<u64>,
+    /// Board to play on instead of the default 40x20 one.
+    pub board: Option<BoardConfig>,
+    /// Command starting an external bot that steers the snake.
+    pub bot: Option<String>,
+    /// How long the bot may think each tick before the snake keeps going.
+    pub bot_timeout: Duration,
+    /// Address to listen on for bots connecting over TCP.
+    pub bot_server: Option<String>,
+    /// Whether the game waits for the TCP bot every tick.
+    pub bot_mode: BotMode,
+    /// Address to publish every game on for spectators.
+    pub broadcast: Option<String>,
+    /// Address of a game to watch instead of playing.
+    pub spectate: Option<String>,
+    /// How the board is drawn until another display is picked in the menu.
+    pub render_mode: RenderMode,
+    /// Characters the snakes are drawn with, detected from the terminal
+    /// when absent.
+    pub glyphs: Option<Glyphs>,
+}
+
+/// Why the command line of the `snake` or `snake-bench` binary was rejected.
+#[derive(Debug)]
+pub enum CliError {
+    MissingValue(&'static str),
+    InvalidValue(&'static str, String),
+    UnknownArgument(String),
+    /// Values that are valid on their own but not together.
+    Conflict(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::MissingValue(flag) => write!(f, "missing value for {}", flag),
+            CliError::InvalidValue(flag, value) => {
+                write!(f, "invalid value '{}' for {}", value, flag)
+            }
+            CliError::UnknownArgument(argument) => write!(f, "unknown argument '{}'", argument),
+            CliError::Conflict(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub const USAGE: &str = "Usage: snake [--seed <number>] [--board <width>x<height>] \
+[--bot <command>] [--bot-timeout <milliseconds>] \
+[--bot-server <address>] [--bot-mode lockstep|realtime] \
+[--broadcast <address>] [--spectate <address>] \
+[--display classic|half-block|double-width] [--glyphs unicode|ascii]";
+
+/// Default time a bot has to answer each tick.
+pub const DEFAULT_BOT_TIMEOUT: Duration = Duration::from_millis(100);
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            board: None,
+            bot: None,
+            bot_timeout: DEFAULT_BOT_TIMEOUT,
+            bot_server: None,
+            bot_mode: BotMode::default(),
+            broadcast: None,
+            spectate: None,
+            render_mode: RenderMode::default(),
+            glyphs: None,
+        }
+    }
+}
+
+impl Options {
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self, CliError> {
+        let mut options = Options::default();
+
+        while let Some(argument) = args.next() {
+            match argument.as_str() {
+                "--seed" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--seed"))?;
+                    let seed = value
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue("--seed", value))?;
+                    options.seed = Some(seed);
+                }
+                "--board" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--board"))?;
+                    let board = value
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue("--board", value))?;
+                    options.board = Some(board);
+                }
+                "--bot" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--bot"))?;
+                    options.bot = Some(value);
+                }
+                "--bot-timeout" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--bot-timeout"))?;
+                    let milliseconds = value
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue("--bot-timeout", value))?;
+                    options.bot_timeout = Duration::from_millis(milliseconds);
+                }
+                "--bot-server" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--bot-server"))?;
+                    options.bot_server = Some(value);
+                }
+                "--bot-mode" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--bot-mode"))?;
+                    options.bot_mode = value
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue("--bot-mode", value))?;
+                }
+                "--broadcast" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--broadcast"))?;
+                    options.broadcast = Some(value);
+                }
+                "--spectate" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--spectate"))?;
+                    options.spectate = Some(value);
+                }
+                "--display" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--display"))?;
+                    options.render_mode = value
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue("--display", value))?;
+                }
+                "--glyphs" => {
+                    let value = args.next().ok_or(CliError::MissingValue("--glyphs"))?;
+                    let glyphs = value
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue("--glyphs", value))?;
+                    options.glyphs = Some(glyphs);
+                }
+                _ => return Err(CliError::UnknownArgument(argument)),
+            }
+        }
+
+        Ok(options)
+    }
+}
@@ -0,0 +1,122 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use snake::high_scores::MAX_NAME_LENGTH;
+
+/// Longest address that can be typed in, e.g. `[2001:db8::1]:7879`.
+const MAX_ADDRESS_LENGTH: usize = 64;
+
+/// Prompt asking for the player's name and the address to host a network
+/// game on or to join one at.
+pub struct ConnectForm {
+    title: &'static str,
+    address_label: &'static str,
+    pub name: String,
+    pub address: String,
+    /// Whether the address field is being edited rather than the name.
+    editing_address: bool,
+}
+
+impl ConnectForm {
+    pub fn new(
+        title: &'static str,
+        address_label: &'static str,
+        name: &str,
+        address: &str,
+    ) -> Self {
+        Self {
+            title,
+            address_label,
+            name: name.to_string(),
+            address: address.to_string(),
+            editing_address: false,
+        }
+    }
+
+    /// Switches between the name and the address field.
+    pub fn toggle_field(&mut self) {
+        self.editing_address = !self.editing_address;
+    }
+
+    pub fn push(&mut self, character: char) {
+        if character.is_control() {
+            return;
+        }
+
+        if self.editing_address {
+            if !character.is_whitespace() && self.address.chars().count() < MAX_ADDRESS_LENGTH {
+                self.address.push(character);
+            }
+        } else if self.name.chars().count() < MAX_NAME_LENGTH {
+            self.name.push(character);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        if self.editing_address {
+            self.address.pop();
+        } else {
+            self.name.pop();
+        }
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            self.title,
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let field = |value: &str, editing: bool| {
+            let style = if editing {
+                Style::default().bg(Color::Yellow).fg(Color::Black)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![Span::styled(value.to_string(), style)];
+            if editing {
+                spans.push(Span::styled(
+                    "_",
+                    Style::default().add_modifier(Modifier::SLOW_BLINK),
+                ));
+            }
+            Spans::from(spans)
+        };
+
+        let text = vec![
+            Spans::from("Your name:"),
+            field(&self.name, !self.editing_address),
+            Spans::from(""),
+            Spans::from(self.address_label),
+            field(&self.address, self.editing_address),
+            Spans::from(""),
+            Spans::from("Tab: switch field  Enter: continue  Esc: back"),
+        ];
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
@@ -1,2 +1,5 @@
 pub const BOARD_WIDTH: i32 = 40;
 pub const BOARD_HEIGHT: i32 = 20;
+pub const MIN_BOARD_WIDTH: i32 = 5;
+pub const MIN_BOARD_HEIGHT: i32 = 5;
+pub const HUD_WIDTH: u16 = 24;
@@ -7,7 +7,7 @@ use tui::{
     Frame,
 };
 
-use crate::game::GameDifficulty;
+use snake::game::GameDifficulty;
 
 pub struct DifficultyMenu {
     items: Vec<(&'static str, GameDifficulty)>, // Difficulty name and speed
@@ -1,10 +1,6 @@
 use rand::Rng;
 
-use crate::{
-    constants::{BOARD_HEIGHT, BOARD_WIDTH},
-    coordinate::Coordinate,
-    snake::Snake,
-};
+use crate::{board::BoardConfig, coordinate::Coordinate, snake::Snake};
 
 #[derive(Debug)]
 pub struct Food {
@@ -12,21 +8,37 @@ pub struct Food {
 }
 
 impl Food {
-    pub fn new(snake: &Snake) -> Self {
-        let mut rng = rand::thread_rng();
-        let mut position;
+    /// Places food on a random free cell, drawing from the given RNG so that
+    /// seeded games always spawn food in the same places. Returns `None` when
+    /// the snake fills the whole board.
+    pub fn new<R: Rng>(board: &BoardConfig, snake: &Snake, rng: &mut R) -> Option<Self> {
+        Self::place(board, snake.body(), rng)
+    }
+
+    /// Places food on a random cell that is not in `occupied`, e.g. the
+    /// bodies of several snakes sharing the board. Returns `None` when no
+    /// cell is free.
+    pub fn place<'a, R: Rng, I: IntoIterator<Item = &'a Coordinate>>(
+        board: &BoardConfig,
+        occupied: I,
+        rng: &mut R,
+    ) -> Option<Self> {
+        let mut is_occupied = vec![false; (board.width * board.height) as usize];
+        for segment in occupied {
+            is_occupied[(segment.1 * board.width + segment.0) as usize] = true;
+        }
 
-        loop {
-            position = Coordinate(
-                rng.gen_range(1..BOARD_WIDTH - 1),
-                rng.gen_range(1..BOARD_HEIGHT - 1),
-            );
+        let free_cells: Vec<Coordinate> = (1..board.height - 1)
+            .flat_map(|y| (1..board.width - 1).map(move |x| Coordinate(x, y)))
+            .filter(|cell| !is_occupied[(cell.1 * board.width + cell.0) as usize])
+            .collect();
 
-            if !snake.body().contains(&position) {
-                break;
-            }
+        if free_cells.is_empty() {
+            return None;
         }
 
-        Self { position }
+        Some(Self {
+            position: free_cells[rng.gen_range(0..free_cells.len())],
+        })
     }
 }
@@ -1,23 +1,27 @@
-use crate::constants::{BOARD_HEIGHT, BOARD_WIDTH};
-use crate::coordinate::Coordinate;
-use crate::direction::Direction;
-use crate::food::Food;
+use crate::board::BoardConfig;
 use crate::input::InputHandler;
-use crate::snake::Snake;
-use crate::{music, sound};
-use crossterm::style::{Color, ResetColor, SetBackgroundColor, SetForegroundColor};
-use crossterm::{
-    cursor::{Hide, MoveTo, Show},
-    execute,
-    style::Print,
-    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType},
+use crate::net::{
+    protocol::{SpectatorFrame, SpectatorMessage},
+    Broadcaster,
 };
-use std::io::{stdout, Write};
+use crate::paths;
+use crate::replay::Replay;
+use crate::screen::{BoardStyle, BoardWidget, GameScreen, Overlay};
+use crate::simulation::{DeathCause, Simulation, StepOutcome};
+use crate::strategy::Strategy;
+use crate::{music, sound};
+use crossterm::event::{read, Event, KeyCode};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::{thread, time};
+use tui::{backend::Backend, style::Color, text::Spans, Terminal};
+
+/// How long the result of a game stays on the board before moving on.
+pub(crate) const GAME_OVER_DELAY: time::Duration = time::Duration::from_secs(1);
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameDifficulty {
     EASY,
     MEDIUM,
@@ -25,6 +29,19 @@ pub enum GameDifficulty {
 }
 
 impl GameDifficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameDifficulty::EASY => "Easy",
+            GameDifficulty::MEDIUM => "Medium",
+            GameDifficulty::HARD => "Hard",
+        }
+    }
+
+    /// Time between two ticks at this difficulty.
+    pub fn tick_duration(&self) -> time::Duration {
+        time::Duration::from_millis(self.convert_to_number() as u64)
+    }
+
     fn convert_to_number(&self) -> u8 {
         match self {
             GameDifficulty::EASY => 150,
@@ -34,219 +51,435 @@ impl GameDifficulty {
     }
 }
 
+/// Statistics of a finished game, as shown on the game over screen.
+#[derive(Debug, Clone)]
+pub struct GameSummary {
+    pub score: u32,
+    /// Number of segments of the snake when the game ended.
+    pub length: usize,
+    pub ticks: u64,
+    /// In-game time, i.e. the number of ticks times the tick duration.
+    pub time_survived: time::Duration,
+    pub death_cause: Option<DeathCause>,
+    /// Whether the snake filled the whole board.
+    pub won: bool,
+    pub seed: u64,
+    /// Where the replay of the game was saved, or why it could not be.
+    pub replay_path: Result<PathBuf, String>,
+}
+
+/// How a game run in the terminal came to an end.
+#[derive(Debug, Clone)]
+pub enum GameExit {
+    /// The snake died or filled the board.
+    Finished(GameSummary),
+    /// The player asked for a new game with the same settings from the pause menu.
+    Restart,
+    /// The player left the game from the pause menu.
+    QuitToMenu,
+}
+
+/// What the player picked in the pause menu, other than resuming the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PauseChoice {
+    Restart,
+    QuitToMenu,
+}
+
+/// Entries of the pause menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PauseItem {
+    Resume,
+    Restart,
+    Settings,
+    QuitToMenu,
+}
+
+impl PauseItem {
+    fn label(&self) -> &'static str {
+        match self {
+            PauseItem::Resume => "Resume",
+            PauseItem::Restart => "Restart",
+            PauseItem::Settings => "Settings",
+            PauseItem::QuitToMenu => "Quit to Menu",
+        }
+    }
+}
+
+/// Terminal front end for a [`Simulation`]: polls the keyboard (or asks the
+/// autopilot strategy), plays audio, renders the board and paces the ticks
+/// according to the difficulty.
 pub struct Game {
-    snake: Snake,
-    food: Food,
+    simulation: Simulation,
     input_handler: InputHandler,
-    score: u32,
     game_difficulty: GameDifficulty,
-    is_autopilot_on: bool,
-    sound_enabled: bool,
-    music_enabled: bool,
+    autopilot: Option<Box<dyn Strategy>>,
+    audio: Audio,
+    /// Where the game is published to spectators, with the name of the player.
+    broadcaster: Option<(Broadcaster, String)>,
+    board_style: BoardStyle,
 }
 
 impl Game {
     pub fn new(
         game_difficulty: GameDifficulty,
-        is_autopilot_on: bool,
+        autopilot: Option<Box<dyn Strategy>>,
         sound_enabled: bool,
         music_enabled: bool,
+        seed: u64,
+        board: BoardConfig,
     ) -> Self {
-        let snake = Snake::new();
-        let food = Food::new(&snake);
         Self {
-            snake,
-            food,
+            simulation: Simulation::new(board, seed),
             input_handler: InputHandler::new(),
-            score: 0,
             game_difficulty,
-            is_autopilot_on,
-            sound_enabled,
-            music_enabled,
+            autopilot,
+            audio: Audio::new(sound_enabled, music_enabled),
+            broadcaster: None,
+            board_style: BoardStyle::default(),
         }
     }
 
-    pub fn run(&mut self) -> bool {
-        enable_raw_mode().unwrap();
-        let mut stdout = stdout();
-        execute!(stdout, Hide).unwrap();
-
-        // Create a stop signal for the background music thread
-        let stop_signal = Arc::new(AtomicBool::new(false));
-        let music_stop_signal = stop_signal.clone();
+    /// Publishes every tick of the game to the spectators of `broadcaster`,
+    /// showing the snake as played by `player_name`.
+    pub fn broadcast_to(&mut self, broadcaster: Broadcaster, player_name: &str) {
+        self.broadcaster = Some((broadcaster, player_name.to_string()));
+    }
 
-        let theme_notes = music::game_theme();
+    /// Draws the board in `board_style` instead of the default one.
+    pub fn set_board_style(&mut self, board_style: BoardStyle) {
+        self.board_style = board_style;
+    }
 
-        // Start the background music thread
-        if self.music_enabled {
-            thread::spawn(move || {
-                music::play_music(theme_notes, music_stop_signal);
-            });
-        }
+    /// Plays the game in `terminal` until the snake dies or the player
+    /// leaves it from the pause menu.
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> GameExit {
+        self.audio.start_music();
 
         loop {
-            if self.is_autopilot_on {
-                self.autopilot();
-            } else if self.input_handler.poll_input() {
-                let direction = self.input_handler.get_direction();
-                self.snake.change_direction(direction);
-            }
+            self.input_handler
+                .poll_input(self.simulation.snake().direction);
+
+            if self.input_handler.take_pause_request() {
+                self.publish_frame(true);
+                let screen = self.screen();
+                if let Some(choice) = pause_menu(&mut self.audio, terminal, &screen) {
+                    self.publish_game_over();
+                    self.audio.stop_music();
+                    return match choice {
+                        PauseChoice::Restart => GameExit::Restart,
+                        PauseChoice::QuitToMenu => GameExit::QuitToMenu,
+                    };
+                }
 
-            self.snake.move_forward();
+                // Show the frozen board for a whole tick before moving on again
+                draw_screen(terminal, self.screen());
+                thread::sleep(self.game_difficulty.tick_duration());
+                continue;
+            }
 
-            if self.snake.head_position() == self.food.position {
-                self.snake.grow();
-                self.food = Food::new(&self.snake);
-                self.score += 1;
+            let input = if let Some(strategy) = self.autopilot.as_mut() {
+                Some(strategy.next_direction(&self.simulation.view()))
+            } else {
+                self.input_handler.next_turn()
+            };
 
-                if self.sound_enabled {
-                    thread::spawn(|| {
-                        sound::play_tone(440, 200);
-                    });
+            match self.simulation.step(input) {
+                StepOutcome::Moved => {}
+                StepOutcome::Ate => self.audio.play_eat(),
+                StepOutcome::Died(_) | StepOutcome::Won => {
+                    self.publish_frame(false);
+                    self.publish_game_over();
+
+                    let shown = time::Instant::now();
+                    let title = if self.simulation.is_won() {
+                        "Board Filled!"
+                    } else {
+                        "Game Over"
+                    };
+                    let score = format!("Score: {}", self.simulation.score());
+                    draw_screen(
+                        terminal,
+                        self.screen().overlay(Overlay::message(title, vec![score])),
+                    );
+
+                    if self.simulation.is_won() {
+                        self.audio.play_fanfare();
+                    } else {
+                        self.audio.play_death();
+                    }
+
+                    self.audio.stop_music();
+                    thread::sleep(GAME_OVER_DELAY.saturating_sub(shown.elapsed()));
+
+                    return GameExit::Finished(self.summary());
                 }
             }
 
-            if self.snake.collides_with_self() || self.snake.collides_with_wall() {
-                if self.sound_enabled {
-                    sound::play_tone(220, 500);
-                }
-
-                // Signal the music thread to stop
-                stop_signal.store(true, Ordering::SeqCst);
-
-                self.print_game_over_screen(stdout);
+            draw_screen(terminal, self.screen());
+            self.publish_frame(false);
+            thread::sleep(self.game_difficulty.tick_duration());
+        }
+    }
 
-                return true;
-            }
+    fn publish_frame(&self, paused: bool) {
+        if let Some((broadcaster, player_name)) = &self.broadcaster {
+            broadcaster.publish(&SpectatorMessage::Frame(SpectatorFrame::from_simulation(
+                &self.simulation,
+                player_name,
+                paused,
+            )));
+        }
+    }
 
-            self.render();
-            thread::sleep(time::Duration::from_millis(
-                self.game_difficulty.convert_to_number() as u64,
-            ));
+    /// Tells the spectators how the game ended, or that the player left it.
+    fn publish_game_over(&self) {
+        if let Some((broadcaster, player_name)) = &self.broadcaster {
+            let score = self.simulation.score();
+            let message = if self.simulation.is_won() {
+                format!("{} filled the board with {} points!", player_name, score)
+            } else if self.simulation.death_cause().is_some() {
+                format!("Game over: {} scored {} points.", player_name, score)
+            } else {
+                format!("{} left the game with {} points.", player_name, score)
+            };
+            broadcaster.publish(&SpectatorMessage::GameOver { message });
         }
     }
 
-    fn autopilot(&mut self) {
-        let snake_head = self.snake.head_position();
-        let food_position = self.food.position;
+    pub fn sound_enabled(&self) -> bool {
+        self.audio.sound_enabled
+    }
+
+    pub fn music_enabled(&self) -> bool {
+        self.audio.music_enabled
+    }
 
-        // Calculate the direction to food, considering obstacles
-        let direction_to_food = self.calculate_direction_to_food(snake_head, food_position);
+    pub fn simulation(&self) -> &Simulation {
+        &self.simulation
+    }
 
-        // Change the snake's direction
-        self.snake.change_direction(direction_to_food);
+    /// Saves the finished game to the replay directory and sums it up.
+    fn summary(&self) -> GameSummary {
+        let replay = Replay::from_simulation(&self.simulation, self.game_difficulty);
+        let replay_path = replay
+            .save(&paths::replay_dir())
+            .map_err(|error| error.to_string());
+
+        GameSummary {
+            score: self.simulation.score(),
+            length: self.simulation.snake().body().len(),
+            ticks: self.simulation.ticks(),
+            time_survived: self.time_played(),
+            death_cause: self.simulation.death_cause(),
+            won: self.simulation.is_won(),
+            seed: self.simulation.seed(),
+            replay_path,
+        }
     }
 
-    fn calculate_direction_to_food(&self, head: Coordinate, food: Coordinate) -> Direction {
-        // Helper function to check if the next position in a given direction is safe
-        let is_safe = |direction: Direction| -> bool {
-            let next_position = match direction {
-                Direction::Up => Coordinate(head.0, head.1 - 1),
-                Direction::Down => Coordinate(head.0, head.1 + 1),
-                Direction::Left => Coordinate(head.0 - 1, head.1),
-                Direction::Right => Coordinate(head.0 + 1, head.1),
-            };
-            !self.snake.body.contains(&next_position)
-                && next_position.0 > 0
-                && next_position.0 < BOARD_WIDTH - 1
-                && next_position.1 > 0
-                && next_position.1 < BOARD_HEIGHT - 1
-        };
+    /// In-game time, i.e. the number of ticks times the tick duration.
+    fn time_played(&self) -> time::Duration {
+        self.game_difficulty.tick_duration() * self.simulation.ticks() as u32
+    }
 
-        // Check all directions and choose the best one
-        let directions = [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
+    /// The board with the score and settings in the side panel.
+    fn screen(&self) -> GameScreen {
+        let simulation = &self.simulation;
+        let board = BoardWidget::new(simulation.board(), self.board_style)
+            .snake(
+                simulation.snake().body(),
+                simulation.snake().direction,
+                Color::LightYellow,
+            )
+            .food(simulation.food().position);
+
+        let seconds = self.time_played().as_secs();
+        let mut hud = vec![
+            Spans::from(format!("Score: {}", simulation.score())),
+            Spans::from(format!("Length: {}", simulation.snake().body().len())),
+            Spans::from(format!("Time: {}:{:02}", seconds / 60, seconds % 60)),
+            Spans::from(""),
+            Spans::from(format!("Difficulty: {}", self.game_difficulty.name())),
+            Spans::from(format!("Mode: {}", simulation.board().topology.name())),
+            Spans::from(format!("Board: {}", simulation.board())),
         ];
-
-        let mut best_direction = self.snake.direction;
-        let mut min_distance = i32::MAX;
-
-        for &dir in &directions {
-            if dir != self.snake.direction.opposite() && is_safe(dir) {
-                let next_pos = match dir {
-                    Direction::Up => Coordinate(head.0, head.1 - 1),
-                    Direction::Down => Coordinate(head.0, head.1 + 1),
-                    Direction::Left => Coordinate(head.0 - 1, head.1),
-                    Direction::Right => Coordinate(head.0 + 1, head.1),
-                };
-                let distance = (next_pos.0 - food.0).abs() + (next_pos.1 - food.1).abs();
-                if distance < min_distance {
-                    min_distance = distance;
-                    best_direction = dir;
-                }
-            }
+        if let Some(strategy) = &self.autopilot {
+            hud.push(Spans::from(format!("Autopilot: {}", strategy.name())));
         }
+        hud.push(Spans::from(""));
+        hud.push(Spans::from("P/Esc: pause"));
 
-        best_direction
+        GameScreen::new(board, "Snake", hud)
     }
+}
 
-    /// Clears entire screen, moves cursor to the top left corner, and prints out the game score.
-    fn print_game_over_screen(&mut self, mut stdout: std::io::Stdout) {
-        execute!(stdout, Show).unwrap();
-        clear_screen(&stdout);
+/// Sound effects and background music of a game, which can be toggled from
+/// the pause menu while it runs.
+pub(crate) struct Audio {
+    pub(crate) sound_enabled: bool,
+    pub(crate) music_enabled: bool,
+    music_stop_signal: Option<Arc<AtomicBool>>,
+    music_pause_signal: Arc<AtomicBool>,
+}
 
-        move_cursor_to_top_left_corner(stdout);
-        disable_raw_mode().unwrap();
-        println!("Game Over! Your score: {}", self.score);
+impl Audio {
+    pub(crate) fn new(sound_enabled: bool, music_enabled: bool) -> Self {
+        Self {
+            sound_enabled,
+            music_enabled,
+            music_stop_signal: None,
+            music_pause_signal: Arc::new(AtomicBool::new(false)),
+        }
     }
 
-    fn render(&self) {
-        let mut stdout = stdout();
-        execute!(stdout, Clear(ClearType::All)).unwrap();
+    /// Starts the background music thread, unless music is off or already playing.
+    pub(crate) fn start_music(&mut self) {
+        if !self.music_enabled || self.music_stop_signal.is_some() {
+            return;
+        }
 
-        let (columns, rows) = crossterm::terminal::size().unwrap();
-        let x_offset = (columns as i32 - BOARD_WIDTH) / 2;
-        let y_offset = (rows as i32 - BOARD_HEIGHT) / 2;
+        let stop_signal = Arc::new(AtomicBool::new(false));
+        let music_stop_signal = stop_signal.clone();
+        let music_pause_signal = self.music_pause_signal.clone();
+        let theme_notes = music::game_theme();
 
-        for y in 0..BOARD_HEIGHT {
-            for x in 0..BOARD_WIDTH {
-                let mut symbol = ' ';
-                let foreground_color = Color::Reset;
-                let mut background_color = Color::Black;
+        thread::spawn(move || {
+            music::play_music(theme_notes, music_stop_signal, music_pause_signal);
+        });
 
-                if x == 0 || x == BOARD_WIDTH - 1 || y == 0 || y == BOARD_HEIGHT - 1 {
-                    symbol = ' ';
-                    background_color = Color::Blue;
-                } else if self.snake.body().contains(&Coordinate(x, y)) {
-                    symbol = '@';
-                    background_color = Color::Yellow;
-                } else if self.food.position == Coordinate(x, y) {
-                    symbol = '*';
-                    background_color = Color::Red;
-                }
+        self.music_stop_signal = Some(stop_signal);
+    }
 
-                execute!(
-                    stdout,
-                    MoveTo((x + x_offset) as u16, (y + y_offset) as u16),
-                    SetForegroundColor(foreground_color),
-                    SetBackgroundColor(background_color),
-                    Print(symbol),
-                    ResetColor
-                )
-                .unwrap();
-            }
+    /// Signals the background music thread to stop.
+    pub(crate) fn stop_music(&mut self) {
+        if let Some(stop_signal) = self.music_stop_signal.take() {
+            stop_signal.store(true, Ordering::SeqCst);
         }
+    }
 
-        execute!(
-            stdout,
-            MoveTo(0, 0),
-            SetForegroundColor(Color::Yellow),
-            Print(format!("Score: {}", self.score)),
-            ResetColor
-        )
-        .unwrap();
+    fn set_music_paused(&self, paused: bool) {
+        self.music_pause_signal.store(paused, Ordering::SeqCst);
+    }
+
+    /// Short beep played in the background when a snake eats.
+    pub(crate) fn play_eat(&self) {
+        if self.sound_enabled {
+            thread::spawn(|| {
+                sound::play_tone(440, 200);
+            });
+        }
+    }
+
+    pub(crate) fn play_death(&self) {
+        if self.sound_enabled {
+            sound::play_tone(220, 500);
+        }
+    }
 
-        stdout.flush().unwrap();
+    /// Rising fanfare for a won game.
+    pub(crate) fn play_fanfare(&self) {
+        if self.sound_enabled {
+            for frequency in [440, 554, 659, 880] {
+                sound::play_tone(frequency, 150);
+            }
+        }
     }
 }
 
-fn move_cursor_to_top_left_corner(mut stdout: std::io::Stdout) {
-    execute!(stdout, MoveTo(0, 0)).unwrap();
+/// Freezes the game and music and shows the pause menu over `screen`.
+/// Returns `None` when the game is resumed.
+pub(crate) fn pause_menu<B: Backend>(
+    audio: &mut Audio,
+    terminal: &mut Terminal<B>,
+    screen: &GameScreen,
+) -> Option<PauseChoice> {
+    audio.set_music_paused(true);
+
+    let items = [
+        PauseItem::Resume,
+        PauseItem::Restart,
+        PauseItem::Settings,
+        PauseItem::QuitToMenu,
+    ];
+    let mut selected = 0;
+    let mut is_in_settings = false;
+
+    let choice = loop {
+        let (title, lines, item_count) = if is_in_settings {
+            let lines = vec![
+                format!("Sound: {}", if audio.sound_enabled { "On" } else { "Off" }),
+                format!("Music: {}", if audio.music_enabled { "On" } else { "Off" }),
+                "Back".to_string(),
+            ];
+            ("Settings", lines, 3)
+        } else {
+            let lines = items.iter().map(|item| item.label().to_string()).collect();
+            ("Paused", lines, items.len())
+        };
+
+        draw_screen(
+            terminal,
+            screen
+                .clone()
+                .overlay(Overlay::menu(title, lines, selected)),
+        );
+
+        let Event::Key(key_event) = read().unwrap() else {
+            continue;
+        };
+
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('w') => {
+                selected = (selected + item_count - 1) % item_count;
+            }
+            KeyCode::Down | KeyCode::Char('s') => selected = (selected + 1) % item_count,
+            KeyCode::Char('p') | KeyCode::Char('P') | KeyCode::Esc => {
+                if !is_in_settings {
+                    break None;
+                }
+                is_in_settings = false;
+                selected = 2;
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if is_in_settings {
+                    match selected {
+                        0 => audio.sound_enabled = !audio.sound_enabled,
+                        1 => {
+                            audio.music_enabled = !audio.music_enabled;
+                            if audio.music_enabled {
+                                audio.start_music();
+                            } else {
+                                audio.stop_music();
+                            }
+                        }
+                        _ => {
+                            is_in_settings = false;
+                            selected = 2;
+                        }
+                    }
+                } else if items[selected] == PauseItem::Settings {
+                    is_in_settings = true;
+                    selected = 0;
+                } else {
+                    break match items[selected] {
+                        PauseItem::Restart => Some(PauseChoice::Restart),
+                        PauseItem::QuitToMenu => Some(PauseChoice::QuitToMenu),
+                        _ => None,
+                    };
+                }
+            }
+            _ => {}
+        }
+    };
+
+    audio.set_music_paused(false);
+    choice
 }
 
-fn clear_screen(mut stdout: &std::io::Stdout) {
-    execute!(stdout, Clear(ClearType::All)).unwrap();
+/// Draws `screen` over the whole terminal.
+pub(crate) fn draw_screen<B: Backend>(terminal: &mut Terminal<B>, screen: GameScreen) {
+    terminal
+        .draw(|f| f.render_widget(screen, f.size()))
+        .unwrap();
 }
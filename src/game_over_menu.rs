@@ -0,0 +1,173 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use snake::game::GameSummary;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverItem {
+    Restart,
+    MainMenu,
+    Quit,
+}
+
+/// Screen shown at the end of a single-player game: the game over screen,
+/// or the victory screen when the snake filled the whole board.
+pub struct GameOverMenu {
+    summary: GameSummary,
+    items: Vec<GameOverItem>,
+    state: ListState,
+}
+
+impl GameOverMenu {
+    pub fn new(summary: GameSummary) -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+
+        Self {
+            summary,
+            items: vec![
+                GameOverItem::Restart,
+                GameOverItem::MainMenu,
+                GameOverItem::Quit,
+            ],
+            state,
+        }
+    }
+
+    pub fn next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let won = self.summary.won;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(if won { 10 } else { 9 }),
+                    Constraint::Min(5),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = if won {
+            Span::styled(
+                "Victory!",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled(
+                "Game Over",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )
+        };
+
+        let seconds = self.summary.time_survived.as_secs();
+        let replay = match &self.summary.replay_path {
+            Ok(path) => format!("Replay: {}", path.display()),
+            Err(error) => format!("Replay not saved: {}", error),
+        };
+
+        let mut text = Vec::new();
+        if won {
+            text.push(Spans::from(Span::styled(
+                "The snake filled the whole board, a perfect game!",
+                Style::default().fg(Color::Green),
+            )));
+            text.push(Spans::from(""));
+        }
+        text.push(Spans::from(format!("Score: {}", self.summary.score)));
+        text.push(Spans::from(format!("Length: {}", self.summary.length)));
+        if won {
+            let ticks_per_food = self.summary.ticks as f64 / self.summary.score.max(1) as f64;
+            text.push(Spans::from(format!(
+                "Time to fill the board: {}:{:02} ({} ticks, {:.1} per food)",
+                seconds / 60,
+                seconds % 60,
+                self.summary.ticks,
+                ticks_per_food
+            )));
+        } else {
+            let cause = match self.summary.death_cause {
+                Some(cause) => format!("Cause of death: {}", cause),
+                None => "Cause of death: Left the game".to_string(),
+            };
+            text.push(Spans::from(format!(
+                "Time survived: {}:{:02} ({} ticks)",
+                seconds / 60,
+                seconds % 60,
+                self.summary.ticks
+            )));
+            text.push(Spans::from(cause));
+        }
+        text.push(Spans::from(format!("Seed: {}", self.summary.seed)));
+        text.push(Spans::from(replay));
+
+        let stats = Paragraph::new(text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Spans::from(vec![title])),
+        );
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|i| {
+                let content = match i {
+                    GameOverItem::Restart if won => "Play Again",
+                    GameOverItem::Restart => "Restart",
+                    GameOverItem::MainMenu => "Main Menu",
+                    GameOverItem::Quit => "Quit",
+                };
+                ListItem::new(Spans::from(content))
+            })
+            .collect();
+
+        let menu = List::new(items)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::Yellow).fg(Color::Black))
+            .highlight_symbol("> ");
+
+        f.render_widget(stats, chunks[1]);
+        f.render_stateful_widget(menu, chunks[2], &mut self.state);
+    }
+
+    pub fn get_selected(&self) -> Option<GameOverItem> {
+        self.state.selected().map(|i| self.items[i])
+    }
+}
@@ -0,0 +1,104 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+use snake::{board::Topology, game::GameDifficulty, high_scores::HighScores};
+
+/// Shows the high score table of one difficulty and board mode at a time.
+pub struct HighScoreMenu {
+    high_scores: HighScores,
+    categories: Vec<(GameDifficulty, Topology)>,
+    selected: usize,
+}
+
+impl HighScoreMenu {
+    pub fn new(high_scores: HighScores, difficulty: GameDifficulty, topology: Topology) -> Self {
+        let mut categories = Vec::new();
+        for topology in [Topology::Walled, Topology::Wrapping] {
+            for difficulty in [
+                GameDifficulty::EASY,
+                GameDifficulty::MEDIUM,
+                GameDifficulty::HARD,
+            ] {
+                categories.push((difficulty, topology));
+            }
+        }
+
+        // Start on the table of the current settings
+        let selected = categories
+            .iter()
+            .position(|&category| category == (difficulty, topology))
+            .unwrap_or(0);
+
+        Self {
+            high_scores,
+            categories,
+            selected,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % self.categories.len();
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = (self.selected + self.categories.len() - 1) % self.categories.len();
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let (difficulty, topology) = self.categories[self.selected];
+        let title = Spans::from(vec![Span::styled(
+            format!(
+                "High Scores - {} / {}  (←/→ to switch)",
+                topology.name(),
+                difficulty.name()
+            ),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let rows: Vec<Row> = self
+            .high_scores
+            .entries(difficulty, topology)
+            .iter()
+            .enumerate()
+            .map(|(rank, entry)| {
+                Row::new(vec![
+                    Cell::from(format!("{}.", rank + 1)),
+                    Cell::from(entry.name.clone()),
+                    Cell::from(entry.score.to_string()),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .header(Row::new(vec!["#", "Name", "Score"]).style(Style::default().fg(Color::Yellow)))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .widths(&[
+                Constraint::Length(4),
+                Constraint::Length(20),
+                Constraint::Length(8),
+            ]);
+
+        f.render_widget(table, chunks[1]);
+    }
+}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    board::{BoardConfig, Topology},
+    game::GameDifficulty,
+};
+
+/// Number of scores kept for each difficulty and board mode.
+pub const MAX_HIGH_SCORES: usize = 10;
+
+/// Longest player name accepted in the high score table.
+pub const MAX_NAME_LENGTH: usize = 16;
+
+/// Whether games on `board` enter the high score tables. Only the default
+/// board size is ranked, as the tables do not tell board sizes apart and
+/// larger boards make for higher scores.
+pub fn is_ranked(board: &BoardConfig) -> bool {
+    let default = BoardConfig::default();
+    (board.width, board.height) == (default.width, default.height)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighScore {
+    pub name: String,
+    pub score: u32,
+    /// Seconds since the Unix epoch when the score was set.
+    pub recorded_at: u64,
+}
+
+impl HighScore {
+    pub fn new(name: &str, score: u32) -> Self {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            name: name.chars().take(MAX_NAME_LENGTH).collect(),
+            score,
+            recorded_at,
+        }
+    }
+}
+
+/// Scores set on one difficulty and board mode, best first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Category {
+    difficulty: GameDifficulty,
+    topology: Topology,
+    entries: Vec<HighScore>,
+}
+
+/// High score tables for every combination of difficulty and board mode,
+/// stored on disk as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HighScores {
+    categories: Vec<Category>,
+}
+
+#[derive(Debug)]
+pub enum HighScoreError {
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for HighScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HighScoreError::Io(error) => write!(f, "could not access high scores: {}", error),
+            HighScoreError::Json(error) => write!(f, "malformed high scores: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for HighScoreError {}
+
+impl From<io::Error> for HighScoreError {
+    fn from(error: io::Error) -> Self {
+        HighScoreError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for HighScoreError {
+    fn from(error: serde_json::Error) -> Self {
+        HighScoreError::Json(error)
+    }
+}
+
+impl HighScores {
+    /// Loads the high scores from `path`. A missing file yields empty tables.
+    pub fn load(path: &Path) -> Result<Self, HighScoreError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(serde_json::from_str(&text)?),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), HighScoreError> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// The scores for a difficulty and board mode, best first.
+    pub fn entries(&self, difficulty: GameDifficulty, topology: Topology) -> &[HighScore] {
+        self.categories
+            .iter()
+            .find(|category| category.difficulty == difficulty && category.topology == topology)
+            .map(|category| category.entries.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Whether `score` would make it into the table for a difficulty and board mode.
+    pub fn qualifies(&self, difficulty: GameDifficulty, topology: Topology, score: u32) -> bool {
+        let entries = self.entries(difficulty, topology);
+        score > 0
+            && (entries.len() < MAX_HIGH_SCORES
+                || entries.last().is_some_and(|lowest| score > lowest.score))
+    }
+
+    /// Adds a score to its table and returns its rank (0 is best), or `None`
+    /// when it did not qualify.
+    pub fn insert(
+        &mut self,
+        difficulty: GameDifficulty,
+        topology: Topology,
+        high_score: HighScore,
+    ) -> Option<usize> {
+        if !self.qualifies(difficulty, topology, high_score.score) {
+            return None;
+        }
+
+        let entries = self.entries_mut(difficulty, topology);
+        // Ties rank below the scores that were set earlier
+        let rank = entries
+            .iter()
+            .position(|entry| high_score.score > entry.score)
+            .unwrap_or(entries.len());
+        entries.insert(rank, high_score);
+        entries.truncate(MAX_HIGH_SCORES);
+
+        Some(rank)
+    }
+
+    fn entries_mut(
+        &mut self,
+        difficulty: GameDifficulty,
+        topology: Topology,
+    ) -> &mut Vec<HighScore> {
+        let index = self.categories.iter().position(|category| {
+            category.difficulty == difficulty && category.topology == topology
+        });
+
+        let index = index.unwrap_or_else(|| {
+            self.categories.push(Category {
+                difficulty,
+                topology,
+                entries: Vec::new(),
+            });
+            self.categories.len() - 1
+        });
+
+        &mut self.categories[index].entries
+    }
+}
@@ -1,41 +1,116 @@
+use std::collections::VecDeque;
 use std::time::Duration;
 
 use crossterm::event::{poll, read, Event, KeyCode};
 
 use crate::direction::Direction;
 
+/// Number of turns that can be queued ahead of the snake.
+pub const MAX_QUEUED_TURNS: usize = 3;
+
+/// Which keys steer a snake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Controls {
+    /// Both the arrow keys and WASD, for a player alone at the keyboard.
+    #[default]
+    Any,
+    Wasd,
+    Arrows,
+}
+
+impl Controls {
+    /// The direction `key` turns the snake in with these controls.
+    pub fn direction(&self, key: KeyCode) -> Option<Direction> {
+        let arrows = matches!(self, Controls::Any | Controls::Arrows);
+        let wasd = matches!(self, Controls::Any | Controls::Wasd);
+
+        match key {
+            KeyCode::Left if arrows => Some(Direction::Left),
+            KeyCode::Right if arrows => Some(Direction::Right),
+            KeyCode::Up if arrows => Some(Direction::Up),
+            KeyCode::Down if arrows => Some(Direction::Down),
+            KeyCode::Char('a') if wasd => Some(Direction::Left),
+            KeyCode::Char('d') if wasd => Some(Direction::Right),
+            KeyCode::Char('w') if wasd => Some(Direction::Up),
+            KeyCode::Char('s') if wasd => Some(Direction::Down),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Controls::Any => "arrows/WASD",
+            Controls::Wasd => "WASD",
+            Controls::Arrows => "arrows",
+        }
+    }
+}
+
+fn is_pause_key(key: KeyCode) -> bool {
+    matches!(key, KeyCode::Char('p') | KeyCode::Char('P') | KeyCode::Esc)
+}
+
+/// Collects key presses between ticks into a bounded queue of turns, so that
+/// quick successive turns (e.g. Up then Left within one tick) are played on
+/// consecutive ticks instead of overwriting each other.
 #[derive(Debug)]
 pub struct InputHandler {
-    direction: Direction,
+    turns: VecDeque<Direction>,
+    pause_requested: bool,
 }
 
 impl InputHandler {
     pub fn new() -> Self {
         Self {
-            direction: Direction::Right,
+            turns: VecDeque::with_capacity(MAX_QUEUED_TURNS),
+            pause_requested: false,
         }
     }
 
-    pub fn poll_input(&mut self) -> bool {
-        if poll(Duration::from_millis(0)).unwrap() {
+    /// Reads every pending key event. `current_direction` is the direction
+    /// the snake is moving in right now, used to validate the queued turns.
+    /// Stops early when the pause key is pressed, leaving the remaining
+    /// events for the pause menu.
+    pub fn poll_input(&mut self, current_direction: Direction) {
+        while poll(Duration::from_millis(0)).unwrap() {
             if let Event::Key(key_event) = read().unwrap() {
-                self.direction = match key_event.code {
-                    KeyCode::Left | KeyCode::Char('a') => Direction::Left,
-                    KeyCode::Right | KeyCode::Char('d') => Direction::Right,
-                    KeyCode::Up | KeyCode::Char('w') => Direction::Up,
-                    KeyCode::Down | KeyCode::Char('s') => Direction::Down,
-                    _ => self.direction,
-                };
-
-                return true;
+                if is_pause_key(key_event.code) {
+                    self.pause_requested = true;
+                    return;
+                }
+
+                if let Some(direction) = Controls::Any.direction(key_event.code) {
+                    self.push_turn(direction, current_direction);
+                }
             }
         }
+    }
+
+    /// Queues a turn unless it would be a no-op or a reversal relative to
+    /// the direction the snake will have after the already queued turns, or
+    /// the queue is full. Returns whether the turn was queued.
+    pub fn push_turn(&mut self, direction: Direction, current_direction: Direction) -> bool {
+        let last_direction = self.turns.back().copied().unwrap_or(current_direction);
+
+        if self.turns.len() >= MAX_QUEUED_TURNS
+            || direction == last_direction
+            || direction == last_direction.opposite()
+        {
+            return false;
+        }
 
-        false
+        self.turns.push_back(direction);
+        true
     }
 
-    pub fn get_direction(&self) -> Direction {
-        self.direction
+    /// Takes the turn to play on this tick, if any.
+    pub fn next_turn(&mut self) -> Option<Direction> {
+        self.turns.pop_front()
+    }
+
+    /// Returns whether the pause key was pressed since the last call.
+    pub fn take_pause_request(&mut self) -> bool {
+        std::mem::take(&mut self.pause_requested)
     }
 }
 
@@ -44,3 +119,66 @@ impl Default for InputHandler {
         Self::new()
     }
 }
+
+/// Input of several players sharing one keyboard, e.g. one on WASD and one
+/// on the arrow keys. Every player has their own queue of turns.
+#[derive(Debug)]
+pub struct SharedKeyboard {
+    players: Vec<(Controls, InputHandler)>,
+    pause_requested: bool,
+}
+
+impl SharedKeyboard {
+    /// One player for each entry of `controls`, in order.
+    pub fn new(controls: &[Controls]) -> Self {
+        Self {
+            players: controls
+                .iter()
+                .map(|&controls| (controls, InputHandler::new()))
+                .collect(),
+            pause_requested: false,
+        }
+    }
+
+    /// Reads every pending key event, like [`InputHandler::poll_input`].
+    /// `current_directions` holds the direction of each player's snake.
+    pub fn poll_input(&mut self, current_directions: &[Direction]) {
+        while poll(Duration::from_millis(0)).unwrap() {
+            if let Event::Key(key_event) = read().unwrap() {
+                if is_pause_key(key_event.code) {
+                    self.pause_requested = true;
+                    return;
+                }
+
+                self.handle_key(key_event.code, current_directions);
+            }
+        }
+    }
+
+    /// Queues the turn `key` stands for on the first player whose controls
+    /// use it. Returns the index of that player.
+    pub fn handle_key(&mut self, key: KeyCode, current_directions: &[Direction]) -> Option<usize> {
+        let (index, direction) =
+            self.players
+                .iter()
+                .enumerate()
+                .find_map(|(index, (controls, _))| {
+                    controls.direction(key).map(|direction| (index, direction))
+                })?;
+
+        self.players[index]
+            .1
+            .push_turn(direction, current_directions[index]);
+        Some(index)
+    }
+
+    /// Takes the turn `player` plays on this tick, if any.
+    pub fn next_turn(&mut self, player: usize) -> Option<Direction> {
+        self.players[player].1.next_turn()
+    }
+
+    /// Returns whether the pause key was pressed since the last call.
+    pub fn take_pause_request(&mut self) -> bool {
+        std::mem::take(&mut self.pause_requested)
+    }
+}
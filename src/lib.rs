@@ -1,9 +1,23 @@
+pub mod bench;
+pub mod board;
+pub mod cli;
 pub mod constants;
 pub mod coordinate;
 pub mod direction;
 pub mod food;
 pub mod game;
+pub mod high_scores;
 pub mod input;
 pub mod music;
+pub mod net;
+pub mod paths;
+pub mod renderer;
+pub mod replay;
+pub mod replay_viewer;
+pub mod screen;
+pub mod simulation;
 pub mod snake;
 pub mod sound;
+pub mod strategy;
+pub mod versus;
+pub mod versus_game;
@@ -0,0 +1,111 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use snake::{net::protocol::LobbyPlayer, versus::MAX_PLAYERS, versus_game::PLAYER_COLORS};
+
+/// Players waiting for a network game to start, in the colors of their
+/// snakes, with whether they are ready and their latency.
+pub struct Lobby {
+    title: String,
+    players: Vec<LobbyPlayer>,
+    /// Index of the player looking at the lobby.
+    you: usize,
+    hint: &'static str,
+}
+
+impl Lobby {
+    pub fn new(title: String, players: Vec<LobbyPlayer>, you: usize) -> Self {
+        Self {
+            title,
+            players,
+            you,
+            hint: "",
+        }
+    }
+
+    pub fn update(&mut self, players: Vec<LobbyPlayer>, you: usize) {
+        self.players = players;
+        self.you = you;
+    }
+
+    /// Sets the keys shown below the players.
+    pub fn set_hint(&mut self, hint: &'static str) {
+        self.hint = hint;
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(MAX_PLAYERS as u16 + 2),
+                    Constraint::Length(3),
+                    Constraint::Min(0),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            self.title.as_str(),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let mut text: Vec<Spans> = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let status = if player.host {
+                    Span::styled("host", Style::default().fg(Color::Yellow))
+                } else if player.ready {
+                    Span::styled("ready", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled("not ready", Style::default().fg(Color::Red))
+                };
+                let latency = match player.latency_ms {
+                    Some(latency) => format!("   {} ms", latency),
+                    None if player.host => String::new(),
+                    None => "   ? ms".to_string(),
+                };
+
+                Spans::from(vec![
+                    Span::styled(
+                        format!("{:<16}", player.name),
+                        Style::default()
+                            .fg(PLAYER_COLORS[index])
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    status,
+                    Span::raw(latency),
+                    Span::raw(if index == self.you { "   (you)" } else { "" }),
+                ])
+            })
+            .collect();
+        for _ in self.players.len()..MAX_PLAYERS {
+            text.push(Spans::from(Span::styled(
+                "Waiting for a player...",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        let players =
+            Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+        let hint = Paragraph::new(self.hint)
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(Alignment::Center);
+
+        f.render_widget(players, chunks[1]);
+        f.render_widget(hint, chunks[2]);
+    }
+}
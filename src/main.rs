@@ -1,42 +1,186 @@
-mod constants;
-mod coordinate;
+mod autopilot_menu;
+mod board_menu;
+mod connect_form;
 mod difficulty_menu;
-mod direction;
-mod food;
-mod game;
-mod input;
+mod display_menu;
+mod game_over_menu;
+mod high_score_menu;
+mod lobby;
 mod menu;
-mod music;
-mod snake;
-mod sound;
+mod mode_menu;
+mod name_entry;
+mod replay_menu;
 mod sound_menu;
+mod winner_menu;
 
+use autopilot_menu::AutopilotMenu;
+use board_menu::BoardMenu;
+use connect_form::ConnectForm;
 use crossterm::{
+    cursor::Show,
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use difficulty_menu::DifficultyMenu;
-use game::{Game, GameDifficulty};
+use display_menu::DisplayMenu;
+use game_over_menu::{GameOverItem, GameOverMenu};
+use high_score_menu::HighScoreMenu;
+use lobby::Lobby;
 use menu::{Menu, MenuItem};
+use mode_menu::ModeMenu;
+use name_entry::NameEntry;
+use replay_menu::ReplayMenu;
+use snake::{
+    board::{BoardConfig, Topology},
+    cli::{self, Options},
+    game::{Game, GameDifficulty, GameExit, GameSummary},
+    high_scores::{self, HighScore, HighScores},
+    input::Controls,
+    net::{
+        protocol::HostMessage, Broadcaster, Client, ClientGame, Host, HostGame, NetworkExit,
+        Spectator, SpectatorView, DEFAULT_PORT,
+    },
+    paths,
+    renderer::{CrosstermRenderer, RendererBackend},
+    replay::{self, Replay},
+    replay_viewer::ReplayViewer,
+    screen::{BoardStyle, Glyphs, RenderMode},
+    strategy::{
+        protocol::{BotMode, Handshake},
+        BotServer, ProcessStrategy, Strategy, StrategyRegistry, TcpStrategy,
+    },
+    versus_game::{computer_opponent, VersusExit, VersusGame, VersusPlayer, VersusSummary},
+};
 use sound_menu::SoundMenu;
-use std::{error::Error, io};
-use tui::{backend::CrosstermBackend, Terminal};
+use std::{error::Error, io, path::PathBuf, time::Duration};
+use tui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Terminal,
+};
+use winner_menu::WinnerMenu;
+
+/// Draws the menus and games on standard output, sending only the cells
+/// that changed.
+type TerminalBackend = RendererBackend<CrosstermRenderer<io::Stdout>>;
+
+/// Settings picked in the menus, shared by every game of the session.
+struct Settings {
+    difficulty: GameDifficulty,
+    board: BoardConfig,
+    topology: Topology,
+    /// How the board is drawn: the render mode given with `--display` or
+    /// picked in the menu, and the snake glyphs given with `--glyphs`.
+    board_style: BoardStyle,
+    sound_enabled: bool,
+    music_enabled: bool,
+    /// Name of the autopilot strategy steering the snake, `None` to play yourself.
+    autopilot: Option<String>,
+    /// Seed given on the command line; every game picks a new one when absent.
+    seed: Option<u64>,
+    /// Name entered for the last high score, offered again for the next one.
+    player_name: String,
+    /// Listener for bots connecting over TCP, given with `--bot-server`.
+    bot_server: Option<BotServer>,
+    bot_mode: BotMode,
+    /// How long a lockstep TCP bot may think about each tick.
+    bot_timeout: Duration,
+    /// Address last used to host a network game.
+    host_address: String,
+    /// Address of the host last joined.
+    join_address: String,
+    /// Where every game is published to spectators, given with `--broadcast`.
+    broadcaster: Option<Broadcaster>,
+}
+
+/// Name under which the bot given with `--bot` is offered as autopilot.
+const BOT_STRATEGY: &str = "Bot";
+/// Name under which bots connecting to `--bot-server` are offered as autopilot.
+const TCP_BOT_STRATEGY: &str = "Bot (TCP)";
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let board_style = BoardStyle {
+        render_mode: options.render_mode,
+        glyphs: options.glyphs.unwrap_or_else(Glyphs::detect),
+    };
+
+    if let Some(address) = options.spectate.as_deref() {
+        return spectate(address, board_style);
+    }
+
+    let mut registry = StrategyRegistry::with_builtin();
+    if let Some(command) = options.bot.clone() {
+        // Fail now rather than in the middle of the first game
+        if let Err(error) = ProcessStrategy::check_command_line(&command) {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+
+        let timeout = options.bot_timeout;
+        registry.register(BOT_STRATEGY, move || {
+            Box::new(ProcessStrategy::from_command_line(&command, timeout))
+        });
+    }
+
+    let bot_server = match options.bot_server.as_deref().map(BotServer::bind) {
+        Some(Ok(server)) => Some(server),
+        Some(Err(error)) => {
+            eprintln!("cannot listen for bots: {}", error);
+            std::process::exit(2);
+        }
+        None => None,
+    };
+
+    let broadcaster = match options.broadcast.as_deref().map(Broadcaster::bind) {
+        Some(Ok(broadcaster)) => Some(broadcaster),
+        Some(Err(error)) => {
+            eprintln!("cannot publish games for spectators: {}", error);
+            std::process::exit(2);
+        }
+        None => None,
+    };
+
     enable_raw_mode()?;
 
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
 
-    let backend = CrosstermBackend::new(stdout);
+    let backend = RendererBackend::new(CrosstermRenderer::new(stdout));
     let mut terminal = Terminal::new(backend)?;
 
     let mut menu = Menu::new();
-    let mut game_difficulty = GameDifficulty::MEDIUM;
-    let mut sound_enabled = true;
-    let mut music_enabled = true;
-    let autopilot = false;
+    let mut settings = Settings {
+        difficulty: GameDifficulty::MEDIUM,
+        board: options.board.unwrap_or_default(),
+        topology: Topology::Walled,
+        board_style,
+        sound_enabled: true,
+        music_enabled: true,
+        autopilot: if bot_server.is_some() {
+            Some(TCP_BOT_STRATEGY.to_string())
+        } else {
+            options.bot.as_ref().map(|_| BOT_STRATEGY.to_string())
+        },
+        seed: options.seed,
+        player_name: String::new(),
+        bot_server,
+        bot_mode: options.bot_mode,
+        bot_timeout: options.bot_timeout,
+        host_address: format!("0.0.0.0:{}", DEFAULT_PORT),
+        join_address: format!("127.0.0.1:{}", DEFAULT_PORT),
+        broadcaster,
+    };
 
     loop {
         terminal.draw(|f| menu.render(f))?;
@@ -49,36 +193,101 @@ fn main() -> Result<(), Box<dyn Error>> {
                 KeyCode::Enter => {
                     match menu.get_selected() {
                         Some(MenuItem::Play) => {
-                            let mut game =
-                                Game::new(game_difficulty, autopilot, sound_enabled, music_enabled);
-
-                            disable_raw_mode()?;
-                            execute!(
-                                terminal.backend_mut(),
-                                LeaveAlternateScreen,
-                                DisableMouseCapture
-                            )?;
+                            let Some(board) = board_for_terminal(&mut terminal, &settings)? else {
+                                continue;
+                            };
 
-                            let is_game_over = game.run();
-
-                            enable_raw_mode()?;
-                            execute!(
-                                terminal.backend_mut(),
-                                EnterAlternateScreen,
-                                EnableMouseCapture
-                            )?;
+                            if play(&mut terminal, &mut settings, &registry, board)?
+                                == GameOverItem::Quit
+                            {
+                                break;
+                            }
+                        }
+                        Some(item @ (MenuItem::Versus | MenuItem::VersusComputer)) => {
+                            let against_computer = matches!(item, MenuItem::VersusComputer);
+                            let Some(board) = board_for_terminal(&mut terminal, &settings)? else {
+                                continue;
+                            };
 
-                            if is_game_over {
+                            if play_versus(&mut terminal, &mut settings, board, against_computer)?
+                                == GameOverItem::Quit
+                            {
                                 break;
                             }
                         }
+                        Some(item @ (MenuItem::HostGame | MenuItem::JoinGame)) => {
+                            let choice = if matches!(item, MenuItem::HostGame) {
+                                host_game(&mut terminal, &mut settings)?
+                            } else {
+                                join_game(&mut terminal, &mut settings)?
+                            };
+                            if choice == GameOverItem::Quit {
+                                break;
+                            }
+                        }
+                        Some(MenuItem::Mode) => {
+                            settings.topology = select_mode(&mut terminal, settings.topology)?;
+                        }
                         Some(MenuItem::Difficulty) => {
                             // Implement difficulty selection logic
-                            game_difficulty = select_difficulty(&mut terminal)?;
+                            settings.difficulty = select_difficulty(&mut terminal)?;
+                        }
+                        Some(MenuItem::BoardSize) => {
+                            settings.board = select_board(
+                                &mut terminal,
+                                settings.board,
+                                settings.board_style.render_mode,
+                            )?;
+                        }
+                        Some(MenuItem::Display) => {
+                            settings.board_style.render_mode =
+                                select_display(&mut terminal, settings.board_style.render_mode)?;
+                        }
+                        Some(MenuItem::Autopilot) => {
+                            let names = registry
+                                .names()
+                                .chain(settings.bot_server.as_ref().map(|_| TCP_BOT_STRATEGY));
+                            settings.autopilot = select_autopilot(
+                                &mut terminal,
+                                names.collect(),
+                                settings.autopilot.take(),
+                            )?;
                         }
                         Some(MenuItem::Sound) => {
                             // Implement sound toggle logic
-                            toggle_sound(&mut terminal, &mut sound_enabled, &mut music_enabled)?;
+                            toggle_sound(
+                                &mut terminal,
+                                &mut settings.sound_enabled,
+                                &mut settings.music_enabled,
+                            )?;
+                        }
+                        Some(MenuItem::HighScores) => {
+                            show_high_scores(
+                                &mut terminal,
+                                settings.difficulty,
+                                settings.topology,
+                            )?;
+                        }
+                        Some(MenuItem::Replays) => {
+                            let Some(path) = select_replay(&mut terminal)? else {
+                                continue;
+                            };
+
+                            let replay = match Replay::load(&path) {
+                                Ok(replay) => replay,
+                                Err(error) => {
+                                    show_message(
+                                        &mut terminal,
+                                        "Cannot Play Replay",
+                                        &error.to_string(),
+                                    )?;
+                                    continue;
+                                }
+                            };
+
+                            let mut viewer = ReplayViewer::new(replay);
+                            viewer.set_board_style(settings.board_style);
+                            viewer.run(&mut terminal);
                         }
                         Some(MenuItem::Quit) => break,
                         None => {}
@@ -92,16 +301,499 @@ fn main() -> Result<(), Box<dyn Error>> {
     disable_raw_mode()?;
 
     execute!(
-        terminal.backend_mut(),
+        terminal.backend_mut().renderer_mut().writer_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        Show
     )?;
 
     Ok(())
 }
 
+/// Watches the games published on `address` with `--broadcast` until the
+/// spectator leaves or the game is no longer published, drawing the board
+/// in `board_style`.
+fn spectate(address: &str, board_style: BoardStyle) -> Result<(), Box<dyn Error>> {
+    let mut spectator = match Spectator::connect(address) {
+        Ok(spectator) => spectator,
+        Err(error) => {
+            eprintln!("cannot watch the game on {}: {}", address, error);
+            std::process::exit(2);
+        }
+    };
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(RendererBackend::new(CrosstermRenderer::new(stdout)))?;
+
+    let mut view = SpectatorView::new();
+    view.set_board_style(board_style);
+    let exit = view.run(&mut terminal, &mut spectator);
+
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut().renderer_mut().writer_mut(),
+        LeaveAlternateScreen,
+        Show
+    )?;
+
+    if let NetworkExit::Disconnected = exit {
+        println!("The game on {} is no longer published.", address);
+    }
+
+    Ok(())
+}
+
+/// The board to play on with the current settings, or `None` after telling
+/// the player that it does not fit into the terminal.
+fn board_for_terminal(
+    terminal: &mut Terminal<TerminalBackend>,
+    settings: &Settings,
+) -> Result<Option<BoardConfig>, Box<dyn Error>> {
+    let (columns, rows) = crossterm::terminal::size()?;
+    let render_mode = settings.board_style.render_mode;
+    if let Err(error) = render_mode.validate_for_terminal(&settings.board, columns, rows) {
+        show_message(
+            terminal,
+            "Board Too Large",
+            &format!("{}. Pick a smaller board size.", error),
+        )?;
+        return Ok(None);
+    }
+
+    Ok(Some(settings.board.with_topology(settings.topology)))
+}
+
+/// Plays games on `board` with the current settings until the player leaves
+/// the game over screen or the pause menu with anything but "Restart".
+fn play(
+    terminal: &mut Terminal<TerminalBackend>,
+    settings: &mut Settings,
+    registry: &StrategyRegistry,
+    board: BoardConfig,
+) -> Result<GameOverItem, Box<dyn Error>> {
+    loop {
+        let autopilot: Option<Box<dyn Strategy>> = match settings.autopilot.as_deref() {
+            Some(TCP_BOT_STRATEGY) => {
+                let Some(server) = &settings.bot_server else {
+                    return Ok(GameOverItem::MainMenu);
+                };
+                let handshake = Handshake::new(settings.bot_mode, board, settings.difficulty);
+                match wait_for_bot(terminal, server, &handshake, settings.bot_timeout)? {
+                    Some(strategy) => Some(Box::new(strategy)),
+                    None => return Ok(GameOverItem::MainMenu),
+                }
+            }
+            Some(name) => registry.create(name),
+            None => None,
+        };
+
+        let seed = settings.seed.unwrap_or_else(rand::random);
+        let mut game = Game::new(
+            settings.difficulty,
+            autopilot,
+            settings.sound_enabled,
+            settings.music_enabled,
+            seed,
+            board,
+        );
+        if let Some(broadcaster) = &settings.broadcaster {
+            let player_name = match settings.autopilot.as_deref() {
+                Some(strategy) => strategy,
+                None if settings.player_name.is_empty() => "Player",
+                None => &settings.player_name,
+            };
+            game.broadcast_to(broadcaster.clone(), player_name);
+        }
+        game.set_board_style(settings.board_style);
+
+        let exit = game.run(terminal);
+
+        // Keep the audio settings changed from the pause menu
+        settings.sound_enabled = game.sound_enabled();
+        settings.music_enabled = game.music_enabled();
+
+        let summary = match exit {
+            GameExit::Finished(summary) => summary,
+            GameExit::Restart => continue,
+            GameExit::QuitToMenu => return Ok(GameOverItem::MainMenu),
+        };
+
+        // Scores set by the autopilot do not belong in the players' table
+        if settings.autopilot.is_none() && high_scores::is_ranked(&board) {
+            record_high_score(
+                terminal,
+                settings.difficulty,
+                settings.topology,
+                summary.score,
+                &mut settings.player_name,
+            )?;
+        }
+
+        match show_game_over(terminal, summary)? {
+            GameOverItem::Restart => continue,
+            choice => return Ok(choice),
+        }
+    }
+}
+
+/// Plays two-player games, either on one keyboard with WASD against the
+/// arrow keys or against a computer opponent as strong as the difficulty,
+/// until the players leave the winner screen or the pause menu with anything
+/// but "Rematch".
+fn play_versus(
+    terminal: &mut Terminal<TerminalBackend>,
+    settings: &mut Settings,
+    board: BoardConfig,
+    against_computer: bool,
+) -> Result<GameOverItem, Box<dyn Error>> {
+    loop {
+        let seed = settings.seed.unwrap_or_else(rand::random);
+        let players = if against_computer {
+            vec![
+                VersusPlayer::human("Player", Controls::Any),
+                VersusPlayer::computer("Computer", computer_opponent(settings.difficulty, seed)),
+            ]
+        } else {
+            vec![
+                VersusPlayer::human("Player 1", Controls::Wasd),
+                VersusPlayer::human("Player 2", Controls::Arrows),
+            ]
+        };
+        let mut game = VersusGame::new(
+            settings.difficulty,
+            players,
+            settings.sound_enabled,
+            settings.music_enabled,
+            seed,
+            board,
+        );
+        if let Some(broadcaster) = &settings.broadcaster {
+            game.broadcast_to(broadcaster.clone());
+        }
+        game.set_board_style(settings.board_style);
+
+        let exit = game.run(terminal);
+
+        // Keep the audio settings changed from the pause menu
+        settings.sound_enabled = game.sound_enabled();
+        settings.music_enabled = game.music_enabled();
+
+        let summary = match exit {
+            VersusExit::Finished(summary) => summary,
+            VersusExit::Restart => continue,
+            VersusExit::QuitToMenu => return Ok(GameOverItem::MainMenu),
+        };
+
+        match show_winner(terminal, summary, "Rematch", &mut || {})? {
+            GameOverItem::Restart => continue,
+            choice => return Ok(choice),
+        }
+    }
+}
+
+/// Hosts network games on the LAN: asks for the address to listen on, then
+/// waits in the lobby for clients to join and get ready. Returns when the
+/// host leaves the lobby, a game or the winner screen with anything but
+/// "Back to Lobby".
+fn host_game(
+    terminal: &mut Terminal<TerminalBackend>,
+    settings: &mut Settings,
+) -> Result<GameOverItem, Box<dyn Error>> {
+    let Some((name, address)) = enter_connect_form(
+        terminal,
+        "Host Game",
+        "Address to listen on:",
+        &settings.player_name,
+        &settings.host_address,
+    )?
+    else {
+        return Ok(GameOverItem::MainMenu);
+    };
+    settings.player_name = name.clone();
+    settings.host_address = address.clone();
+
+    let mut host = match Host::bind(address.as_str(), &name) {
+        Ok(host) => host,
+        Err(error) => {
+            show_message(
+                terminal,
+                "Cannot Host Game",
+                &format!("Cannot listen on {}: {}", address, error),
+            )?;
+            return Ok(GameOverItem::MainMenu);
+        }
+    };
+    let mut lobby = Lobby::new(
+        format!("Lobby - hosting on {}", host.local_addr()?),
+        host.lobby(),
+        0,
+    );
+
+    loop {
+        host.poll();
+        lobby.update(host.lobby(), 0);
+        lobby.set_hint(if host.can_start() {
+            "Enter: start  Esc: leave"
+        } else {
+            "Waiting for everyone to be ready...  Esc: leave"
+        });
+        terminal.draw(|f| lobby.render(f))?;
+
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(GameOverItem::MainMenu),
+            KeyCode::Enter if host.can_start() => {
+                let Some(board) = board_for_terminal(terminal, settings)? else {
+                    continue;
+                };
+
+                let mut game = HostGame::new(
+                    &host,
+                    settings.difficulty,
+                    settings.sound_enabled,
+                    settings.music_enabled,
+                    settings.seed.unwrap_or_else(rand::random),
+                    board,
+                );
+                if let Some(broadcaster) = &settings.broadcaster {
+                    game.broadcast_to(broadcaster.clone());
+                }
+                game.set_board_style(settings.board_style);
+
+                let exit = game.run(terminal, &mut host);
+
+                let NetworkExit::Finished(summary) = exit else {
+                    // Leaving the game closes it for everyone
+                    return Ok(GameOverItem::MainMenu);
+                };
+                match show_winner(terminal, summary, "Back to Lobby", &mut || host.poll())? {
+                    GameOverItem::Restart => {}
+                    choice => return Ok(choice),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Joins a network game: asks for the host's address, then waits in the
+/// host's lobby, where the player gets ready, until the host starts a game.
+/// Returns when the player leaves the lobby, a game or the winner screen with
+/// anything but "Back to Lobby", or the host is gone.
+fn join_game(
+    terminal: &mut Terminal<TerminalBackend>,
+    settings: &mut Settings,
+) -> Result<GameOverItem, Box<dyn Error>> {
+    let Some((name, address)) = enter_connect_form(
+        terminal,
+        "Join Game",
+        "Address of the host:",
+        &settings.player_name,
+        &settings.join_address,
+    )?
+    else {
+        return Ok(GameOverItem::MainMenu);
+    };
+    settings.player_name = name.clone();
+    settings.join_address = address.clone();
+
+    draw_message(
+        terminal,
+        "Join Game",
+        &format!("Connecting to {}...", address),
+        "",
+    )?;
+    let mut client = match Client::connect(address.as_str(), &name) {
+        Ok(client) => client,
+        Err(error) => {
+            show_message(terminal, "Cannot Join Game", &error.to_string())?;
+            return Ok(GameOverItem::MainMenu);
+        }
+    };
+    let mut lobby = Lobby::new(
+        format!("Lobby - connected to {}", client.host_addr()),
+        Vec::new(),
+        0,
+    );
+    let mut ready = false;
+
+    loop {
+        let start = client.poll().into_iter().find_map(|message| match message {
+            HostMessage::Start {
+                board,
+                players,
+                you,
+            } => Some((board, players, you)),
+            _ => None,
+        });
+        if let Some((board, players, you)) = start {
+            let mut game = ClientGame::new(
+                board,
+                players,
+                you,
+                settings.sound_enabled,
+                settings.music_enabled,
+            );
+            game.set_board_style(settings.board_style);
+
+            let exit = game.run(terminal, &mut client);
+
+            // Every game starts with everyone getting ready again
+            ready = false;
+            match exit {
+                NetworkExit::Finished(summary) => {
+                    match show_winner(terminal, summary, "Back to Lobby", &mut || {
+                        client.poll();
+                    })? {
+                        GameOverItem::Restart => {}
+                        choice => return Ok(choice),
+                    }
+                }
+                NetworkExit::Left => return Ok(GameOverItem::MainMenu),
+                NetworkExit::Disconnected => {}
+            }
+        }
+
+        if !client.is_connected() {
+            show_message(
+                terminal,
+                "Disconnected",
+                "The connection to the host was lost.",
+            )?;
+            return Ok(GameOverItem::MainMenu);
+        }
+
+        lobby.update(client.lobby().to_vec(), client.you());
+        lobby.set_hint(if ready {
+            "Space: not ready  Esc: leave"
+        } else {
+            "Space: ready  Esc: leave"
+        });
+        terminal.draw(|f| lobby.render(f))?;
+
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char(' ') | KeyCode::Enter => {
+                    ready = !ready;
+                    client.set_ready(ready);
+                }
+                KeyCode::Esc | KeyCode::Char('q') => return Ok(GameOverItem::MainMenu),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Asks for the player's name and the address to host a network game on or
+/// to join one at. Returns `None` when the player backs out with `Esc`.
+fn enter_connect_form(
+    terminal: &mut Terminal<TerminalBackend>,
+    title: &'static str,
+    address_label: &'static str,
+    name: &str,
+    address: &str,
+) -> Result<Option<(String, String)>, Box<dyn Error>> {
+    let mut connect_form = ConnectForm::new(title, address_label, name, address);
+
+    loop {
+        terminal.draw(|f| connect_form.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char(character) => connect_form.push(character),
+                KeyCode::Backspace => connect_form.pop(),
+                KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                    connect_form.toggle_field()
+                }
+                KeyCode::Enter => break,
+                KeyCode::Esc => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+
+    let name = connect_form.name.trim();
+    let name = if name.is_empty() { "Player" } else { name };
+    Ok(Some((
+        name.to_string(),
+        connect_form.address.trim().to_string(),
+    )))
+}
+
+/// Shows the winner of a versus game. `on_idle` is called regularly while
+/// waiting for a key, e.g. to keep a network connection alive.
+fn show_winner(
+    terminal: &mut Terminal<TerminalBackend>,
+    summary: VersusSummary,
+    restart_label: &'static str,
+    on_idle: &mut dyn FnMut(),
+) -> Result<GameOverItem, Box<dyn Error>> {
+    let mut winner_menu = WinnerMenu::new(summary, restart_label);
+
+    loop {
+        terminal.draw(|f| winner_menu.render(f))?;
+
+        if !event::poll(Duration::from_millis(100))? {
+            on_idle();
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => winner_menu.previous(),
+                KeyCode::Down => winner_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_item) = winner_menu.get_selected() {
+                        return Ok(selected_item);
+                    }
+                }
+                KeyCode::Char('r') => return Ok(GameOverItem::Restart),
+                KeyCode::Char('m') | KeyCode::Esc => return Ok(GameOverItem::MainMenu),
+                KeyCode::Char('q') => return Ok(GameOverItem::Quit),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn show_game_over(
+    terminal: &mut Terminal<TerminalBackend>,
+    summary: GameSummary,
+) -> Result<GameOverItem, Box<dyn Error>> {
+    let mut game_over_menu = GameOverMenu::new(summary);
+
+    loop {
+        terminal.draw(|f| game_over_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => game_over_menu.previous(),
+                KeyCode::Down => game_over_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_item) = game_over_menu.get_selected() {
+                        return Ok(selected_item);
+                    }
+                }
+                KeyCode::Char('r') => return Ok(GameOverItem::Restart),
+                KeyCode::Char('m') | KeyCode::Esc => return Ok(GameOverItem::MainMenu),
+                KeyCode::Char('q') => return Ok(GameOverItem::Quit),
+                _ => {}
+            }
+        }
+    }
+}
+
 fn select_difficulty(
-    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend>,
 ) -> Result<GameDifficulty, Box<dyn Error>> {
     let mut difficulty_menu = DifficultyMenu::new();
 
@@ -126,8 +818,323 @@ fn select_difficulty(
     Ok(GameDifficulty::MEDIUM) // Default to medium difficulty
 }
 
+fn select_mode(
+    terminal: &mut Terminal<TerminalBackend>,
+    current_topology: Topology,
+) -> Result<Topology, Box<dyn Error>> {
+    let mut mode_menu = ModeMenu::new();
+
+    loop {
+        terminal.draw(|f| mode_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => mode_menu.previous(),
+                KeyCode::Down => mode_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_topology) = mode_menu.get_selected_topology() {
+                        return Ok(selected_topology);
+                    }
+                }
+                KeyCode::Char('q') => break, // Keep the current mode if 'q' is pressed
+                _ => {}
+            }
+        }
+    }
+
+    Ok(current_topology)
+}
+
+fn select_display(
+    terminal: &mut Terminal<TerminalBackend>,
+    current_render_mode: RenderMode,
+) -> Result<RenderMode, Box<dyn Error>> {
+    let mut display_menu = DisplayMenu::new();
+
+    loop {
+        terminal.draw(|f| display_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => display_menu.previous(),
+                KeyCode::Down => display_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_render_mode) = display_menu.get_selected_render_mode() {
+                        return Ok(selected_render_mode);
+                    }
+                }
+                KeyCode::Char('q') => break, // Keep the current display if 'q' is pressed
+                _ => {}
+            }
+        }
+    }
+
+    Ok(current_render_mode)
+}
+
+fn select_autopilot(
+    terminal: &mut Terminal<TerminalBackend>,
+    names: Vec<&str>,
+    current_autopilot: Option<String>,
+) -> Result<Option<String>, Box<dyn Error>> {
+    let mut autopilot_menu = AutopilotMenu::new(names);
+
+    loop {
+        terminal.draw(|f| autopilot_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => autopilot_menu.previous(),
+                KeyCode::Down => autopilot_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_strategy) = autopilot_menu.get_selected_strategy() {
+                        return Ok(selected_strategy);
+                    }
+                }
+                KeyCode::Char('q') => break, // Keep the current autopilot if 'q' is pressed
+                _ => {}
+            }
+        }
+    }
+
+    Ok(current_autopilot)
+}
+
+fn select_board(
+    terminal: &mut Terminal<TerminalBackend>,
+    current_board: BoardConfig,
+    render_mode: RenderMode,
+) -> Result<BoardConfig, Box<dyn Error>> {
+    let (columns, rows) = crossterm::terminal::size()?;
+    let mut board_menu = BoardMenu::new(columns, rows, render_mode);
+
+    loop {
+        terminal.draw(|f| board_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => board_menu.previous(),
+                KeyCode::Down => board_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_board) = board_menu.get_selected_board() {
+                        return Ok(selected_board);
+                    }
+                }
+                KeyCode::Char('q') => break, // Keep the current board if 'q' is pressed
+                _ => {}
+            }
+        }
+    }
+
+    Ok(current_board)
+}
+
+/// Asks for the player's name and saves the score when it makes it into the
+/// high score table of the given difficulty and board mode.
+fn record_high_score(
+    terminal: &mut Terminal<TerminalBackend>,
+    difficulty: GameDifficulty,
+    topology: Topology,
+    score: u32,
+    player_name: &mut String,
+) -> Result<(), Box<dyn Error>> {
+    let path = paths::high_scores_path();
+    let mut high_scores = match HighScores::load(&path) {
+        Ok(high_scores) => high_scores,
+        Err(error) => {
+            show_message(terminal, "Cannot Load High Scores", &error.to_string())?;
+            return Ok(());
+        }
+    };
+
+    if !high_scores.qualifies(difficulty, topology, score) {
+        return Ok(());
+    }
+
+    let mut name_entry = NameEntry::new(player_name, score);
+
+    loop {
+        terminal.draw(|f| name_entry.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char(character) => name_entry.push(character),
+                KeyCode::Backspace => name_entry.pop(),
+                KeyCode::Enter => break,
+                KeyCode::Esc => return Ok(()), // Skip saving the score
+                _ => {}
+            }
+        }
+    }
+
+    let name = name_entry.name.trim();
+    let name = if name.is_empty() { "Anonymous" } else { name };
+    *player_name = name.to_string();
+
+    high_scores.insert(difficulty, topology, HighScore::new(name, score));
+    if let Err(error) = high_scores.save(&path) {
+        show_message(terminal, "Cannot Save High Score", &error.to_string())?;
+        return Ok(());
+    }
+
+    show_high_scores(terminal, difficulty, topology)
+}
+
+fn show_high_scores(
+    terminal: &mut Terminal<TerminalBackend>,
+    difficulty: GameDifficulty,
+    topology: Topology,
+) -> Result<(), Box<dyn Error>> {
+    let high_scores = match HighScores::load(&paths::high_scores_path()) {
+        Ok(high_scores) => high_scores,
+        Err(error) => {
+            return show_message(terminal, "Cannot Load High Scores", &error.to_string());
+        }
+    };
+    let mut high_score_menu = HighScoreMenu::new(high_scores, difficulty, topology);
+
+    loop {
+        terminal.draw(|f| high_score_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Left | KeyCode::Up => high_score_menu.previous(),
+                KeyCode::Right | KeyCode::Down => high_score_menu.next(),
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => break,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Lets the player pick one of the saved replays. Returns `None` when there
+/// are no replays or the menu was left without picking one.
+fn select_replay(
+    terminal: &mut Terminal<TerminalBackend>,
+) -> Result<Option<PathBuf>, Box<dyn Error>> {
+    let directory = paths::replay_dir();
+    let replays = replay::list_replays(&directory).unwrap_or_default();
+    if replays.is_empty() {
+        show_message(
+            terminal,
+            "No Replays",
+            &format!("Finished games are saved to {}", directory.display()),
+        )?;
+        return Ok(None);
+    }
+
+    let mut replay_menu = ReplayMenu::new(replays);
+
+    loop {
+        terminal.draw(|f| replay_menu.render(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Up => replay_menu.previous(),
+                KeyCode::Down => replay_menu.next(),
+                KeyCode::Enter => {
+                    if let Some(selected_replay) = replay_menu.get_selected_replay() {
+                        return Ok(Some(selected_replay));
+                    }
+                }
+                KeyCode::Char('q') => break, // Exit if 'q' is pressed
+                _ => {}
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Shows a message box until any key is pressed.
+fn show_message(
+    terminal: &mut Terminal<TerminalBackend>,
+    title: &str,
+    message: &str,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        draw_message(terminal, title, message, "Press any key to continue")?;
+
+        if let Event::Key(_) = event::read()? {
+            return Ok(());
+        }
+    }
+}
+
+/// Waits on a message box until a bot connects to the TCP bot server, and
+/// greets it with `handshake`. Returns `None` when the player cancels with
+/// `Esc` or `q`.
+fn wait_for_bot(
+    terminal: &mut Terminal<TerminalBackend>,
+    server: &BotServer,
+    handshake: &Handshake,
+    timeout: Duration,
+) -> Result<Option<TcpStrategy>, Box<dyn Error>> {
+    let address = server.local_addr()?;
+    let message = format!(
+        "Waiting for a bot to connect to {} ({} mode)...",
+        address, handshake.mode
+    );
+
+    loop {
+        draw_message(terminal, "Bot Server", &message, "Press Esc to cancel")?;
+
+        if let Some(strategy) = server.try_accept(handshake, timeout)? {
+            return Ok(Some(strategy));
+        }
+
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
+fn draw_message(
+    terminal: &mut Terminal<TerminalBackend>,
+    title: &str,
+    message: &str,
+    hint: &str,
+) -> Result<(), Box<dyn Error>> {
+    terminal.draw(|f| {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            title,
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )]);
+
+        let text = vec![Spans::from(message), Spans::from(""), Spans::from(hint)];
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(paragraph, chunks[1]);
+    })?;
+
+    Ok(())
+}
+
 fn toggle_sound(
-    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend>,
     sound_enabled: &mut bool,
     music_enabled: &mut bool,
 ) -> Result<(), Box<dyn Error>> {
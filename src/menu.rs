@@ -9,8 +9,18 @@ use tui::{
 
 pub enum MenuItem {
     Play,
+    Versus,
+    VersusComputer,
+    HostGame,
+    JoinGame,
+    Mode,
     Difficulty,
+    BoardSize,
+    Display,
+    Autopilot,
     Sound,
+    HighScores,
+    Replays,
     Quit,
 }
 
@@ -24,8 +34,18 @@ impl Menu {
         Menu {
             items: vec![
                 MenuItem::Play,
+                MenuItem::Versus,
+                MenuItem::VersusComputer,
+                MenuItem::HostGame,
+                MenuItem::JoinGame,
+                MenuItem::Mode,
                 MenuItem::Difficulty,
+                MenuItem::BoardSize,
+                MenuItem::Display,
+                MenuItem::Autopilot,
                 MenuItem::Sound,
+                MenuItem::HighScores,
+                MenuItem::Replays,
                 MenuItem::Quit,
             ],
             state: ListState::default(),
@@ -87,8 +107,18 @@ impl Menu {
             .map(|i| {
                 let content = match i {
                     MenuItem::Play => "Play",
+                    MenuItem::Versus => "Two Players",
+                    MenuItem::VersusComputer => "Play vs Computer",
+                    MenuItem::HostGame => "Host Game",
+                    MenuItem::JoinGame => "Join Game",
+                    MenuItem::Mode => "Game Mode",
                     MenuItem::Difficulty => "Difficulty",
+                    MenuItem::BoardSize => "Board Size",
+                    MenuItem::Display => "Display",
+                    MenuItem::Autopilot => "Autopilot",
                     MenuItem::Sound => "Sound",
+                    MenuItem::HighScores => "High Scores",
+                    MenuItem::Replays => "Replays",
                     MenuItem::Quit => "Quit",
                 };
                 ListItem::new(Spans::from(content))
@@ -77,9 +77,17 @@ pub enum MusicElement {
 }
 
 /// Plays a sequence of music elements in a loop until the stop signal is received.
-pub fn play_music(elements: Vec<MusicElement>, stop_signal: Arc<AtomicBool>) {
+/// While the pause signal is set, playback holds before the next element.
+pub fn play_music(
+    elements: Vec<MusicElement>,
+    stop_signal: Arc<AtomicBool>,
+    pause_signal: Arc<AtomicBool>,
+) {
     while !stop_signal.load(Ordering::SeqCst) {
         for element in &elements {
+            while pause_signal.load(Ordering::SeqCst) && !stop_signal.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(50));
+            }
             if stop_signal.load(Ordering::SeqCst) {
                 break;
             }
@@ -0,0 +1,78 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use snake::high_scores::MAX_NAME_LENGTH;
+
+/// Prompt asking for the player's name after a high score.
+pub struct NameEntry {
+    pub name: String,
+    score: u32,
+}
+
+impl NameEntry {
+    pub fn new(name: &str, score: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            score,
+        }
+    }
+
+    pub fn push(&mut self, character: char) {
+        if !character.is_control() && self.name.chars().count() < MAX_NAME_LENGTH {
+            self.name.push(character);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        self.name.pop();
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            "New High Score!",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let text = vec![
+            Spans::from(format!("You scored {} points.", self.score)),
+            Spans::from(""),
+            Spans::from("Enter your name:"),
+            Spans::from(vec![
+                Span::styled(
+                    self.name.as_str(),
+                    Style::default().bg(Color::Yellow).fg(Color::Black),
+                ),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            Spans::from(""),
+            Spans::from("Enter: save  Esc: skip"),
+        ];
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    net::{SocketAddr, TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use crate::direction::Direction;
+
+use super::{
+    connection::Connection,
+    protocol::{ClientMessage, HostMessage, LobbyPlayer, PROTOCOL_VERSION},
+    NetError,
+};
+
+/// Longest connecting to the host and joining its lobby may take.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Joins a network game hosted by a [`Host`](super::Host). The client only
+/// sends the player's turns; the host runs the game and sends back what
+/// happened.
+#[derive(Debug)]
+pub struct Client {
+    connection: Connection,
+    lobby: Vec<LobbyPlayer>,
+    you: usize,
+}
+
+impl Client {
+    /// Connects to the host at `address` and joins its lobby as `name`.
+    pub fn connect<A: ToSocketAddrs>(address: A, name: &str) -> Result<Self, NetError> {
+        let mut last_error = None;
+        let mut stream = None;
+        for address in address.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&address, CONNECT_TIMEOUT) {
+                Ok(connected) => {
+                    stream = Some(connected);
+                    break;
+                }
+                Err(error) => last_error = Some(error),
+            }
+        }
+        let Some(stream) = stream else {
+            return Err(last_error.map_or(NetError::Disconnected, NetError::Io));
+        };
+
+        let mut connection = Connection::with_auto_reply(stream, answer_ping)?;
+        connection.send(&ClientMessage::Hello {
+            version: PROTOCOL_VERSION,
+            name: name.to_string(),
+        });
+
+        match connection.recv_timeout(CONNECT_TIMEOUT) {
+            Some(HostMessage::Welcome) => Ok(Self {
+                connection,
+                lobby: Vec::new(),
+                you: 0,
+            }),
+            Some(HostMessage::Rejected { reason }) => Err(NetError::Rejected(reason)),
+            Some(_) | None if connection.is_closed() => Err(NetError::Disconnected),
+            Some(_) | None => Err(NetError::TimedOut),
+        }
+    }
+
+    pub fn host_addr(&self) -> SocketAddr {
+        self.connection.peer()
+    }
+
+    /// Takes every message the host sent since the last call, in order.
+    /// Pings are answered as soon as they arrive and not returned.
+    pub fn poll(&mut self) -> Vec<HostMessage> {
+        let mut messages = Vec::new();
+        while let Some(message) = self.connection.try_recv() {
+            match message {
+                HostMessage::Lobby { players, you } => {
+                    self.lobby = players.clone();
+                    self.you = you;
+                    messages.push(HostMessage::Lobby { players, you });
+                }
+                message => messages.push(message),
+            }
+        }
+        messages
+    }
+
+    /// Whether the host is still there.
+    pub fn is_connected(&self) -> bool {
+        !self.connection.is_closed()
+    }
+
+    /// Everyone in the host's lobby as last sent by the host, the host first.
+    pub fn lobby(&self) -> &[LobbyPlayer] {
+        &self.lobby
+    }
+
+    /// The player's own index in the lobby.
+    pub fn you(&self) -> usize {
+        self.you
+    }
+
+    pub fn set_ready(&mut self, ready: bool) {
+        self.connection.send(&ClientMessage::Ready { ready });
+    }
+
+    pub fn send_turn(&mut self, direction: Direction) {
+        self.connection.send(&ClientMessage::Turn { direction });
+    }
+}
+
+/// Answers a ping from the host, see [`Connection::with_auto_reply`].
+fn answer_ping(line: &str) -> Option<String> {
+    match serde_json::from_str(line) {
+        Ok(HostMessage::Ping { nonce }) => {
+            serde_json::to_string(&ClientMessage::Pong { nonce }).ok()
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{Shutdown, SocketAddr, TcpStream},
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError, TryRecvError},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Longest a message may take to send before the other side counts as gone.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Answers a line right away on the thread reading the connection, e.g. a
+/// ping, so the answer does not wait for the next poll. Lines that get an
+/// answer are not passed on.
+pub(crate) type AutoReply = fn(&str) -> Option<String>;
+
+/// One end of a connection between a host and a client, exchanging
+/// messages as lines of JSON. Lines that do not parse are skipped.
+#[derive(Debug)]
+pub(crate) struct Connection {
+    writer: Arc<Mutex<TcpStream>>,
+    peer: SocketAddr,
+    /// Every line read, with when it arrived.
+    lines: Receiver<(String, Instant)>,
+    closed: bool,
+}
+
+impl Connection {
+    pub(crate) fn new(stream: TcpStream) -> io::Result<Self> {
+        Self::with_auto_reply(stream, |_| None)
+    }
+
+    pub(crate) fn with_auto_reply(stream: TcpStream, auto_reply: AutoReply) -> io::Result<Self> {
+        stream.set_nonblocking(false)?;
+        stream.set_nodelay(true)?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+
+        let reader = stream.try_clone()?;
+        let writer = Arc::new(Mutex::new(stream));
+        let reply_writer = writer.clone();
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else { break };
+                if let Some(reply) = auto_reply(&line) {
+                    let mut stream = reply_writer.lock().unwrap();
+                    if writeln!(stream, "{}", reply).is_err() {
+                        break;
+                    }
+                } else if sender.send((line, Instant::now())).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let peer = writer.lock().unwrap().peer_addr()?;
+        Ok(Self {
+            writer,
+            peer,
+            lines,
+            closed: false,
+        })
+    }
+
+    pub(crate) fn peer(&self) -> SocketAddr {
+        self.peer
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Sends `message`, closing the connection when that fails.
+    pub(crate) fn send<M: Serialize>(&mut self, message: &M) {
+        if self.closed {
+            return;
+        }
+
+        let Ok(line) = serde_json::to_string(message) else {
+            return;
+        };
+        let sent = {
+            let mut stream = self.writer.lock().unwrap();
+            writeln!(stream, "{}", line).and_then(|_| stream.flush())
+        };
+        if sent.is_err() {
+            self.close();
+        }
+    }
+
+    /// Takes the next message that already arrived, if any.
+    pub(crate) fn try_recv<M: DeserializeOwned>(&mut self) -> Option<M> {
+        self.try_recv_timed().map(|(message, _)| message)
+    }
+
+    /// Takes the next message that already arrived, if any, with when it
+    /// arrived.
+    pub(crate) fn try_recv_timed<M: DeserializeOwned>(&mut self) -> Option<(M, Instant)> {
+        loop {
+            match self.lines.try_recv() {
+                Ok((line, received)) => {
+                    if let Ok(message) = serde_json::from_str(&line) {
+                        return Some((message, received));
+                    }
+                }
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    self.close();
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Waits up to `timeout` for the next message.
+    pub(crate) fn recv_timeout<M: DeserializeOwned>(&mut self, timeout: Duration) -> Option<M> {
+        loop {
+            match self.lines.recv_timeout(timeout) {
+                Ok((line, _)) => {
+                    if let Ok(message) = serde_json::from_str(&line) {
+                        return Some(message);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => return None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.close();
+                    return None;
+                }
+            }
+        }
+    }
+
+    pub(crate) fn close(&mut self) {
+        if !self.closed {
+            self.closed = true;
+            let _ = self.writer.lock().unwrap().shutdown(Shutdown::Both);
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
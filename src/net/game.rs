@@ -0,0 +1,520 @@
+use crossterm::event::{poll, read, Event, KeyCode};
+use std::{thread, time};
+use tui::{
+    backend::Backend,
+    layout::Alignment,
+    style::Color,
+    text::Spans,
+    widgets::{Paragraph, Wrap},
+    Terminal,
+};
+
+use crate::board::BoardConfig;
+use crate::coordinate::Coordinate;
+use crate::direction::Direction;
+use crate::game::{draw_screen, Audio, GameDifficulty, GAME_OVER_DELAY};
+use crate::input::{Controls, InputHandler};
+use crate::screen::{player_line, BoardStyle, BoardWidget, GameScreen, Overlay};
+use crate::versus::{VersusOutcome, VersusSimulation};
+use crate::versus_game::{
+    headline, publish_game_over, PlayerSummary, VersusSummary, PLAYER_COLORS,
+};
+
+use super::{
+    protocol::{Frame, HostMessage, SpectatorFrame, SpectatorMessage},
+    Broadcaster, Client, Host, Spectator,
+};
+
+/// How often the client checks for keys and frames from the host.
+const CLIENT_POLL_INTERVAL: time::Duration = time::Duration::from_millis(10);
+
+/// How a network game run in the terminal came to an end.
+#[derive(Debug, Clone)]
+pub enum NetworkExit {
+    /// The game was decided.
+    Finished(VersusSummary),
+    /// The player left the game with `Esc`. When the host leaves, the game
+    /// ends for everyone.
+    Left,
+    /// The connection to the host, or to the game being watched, was lost.
+    Disconnected,
+}
+
+/// Terminal front end of the host of a network game. The host plays the
+/// first snake on the keyboard and runs the [`VersusSimulation`] for
+/// everyone, taking the other snakes' turns from the clients of a [`Host`].
+/// Network games cannot be paused; `Esc` leaves the game.
+pub struct HostGame {
+    simulation: VersusSimulation,
+    names: Vec<String>,
+    keyboard: InputHandler,
+    game_difficulty: GameDifficulty,
+    audio: Audio,
+    broadcaster: Option<Broadcaster>,
+    board_style: BoardStyle,
+}
+
+impl HostGame {
+    /// A game with one snake for every player in the lobby of `host`.
+    pub fn new(
+        host: &Host,
+        game_difficulty: GameDifficulty,
+        sound_enabled: bool,
+        music_enabled: bool,
+        seed: u64,
+        board: BoardConfig,
+    ) -> Self {
+        Self {
+            simulation: VersusSimulation::new(board, host.player_count(), seed),
+            names: host.lobby().into_iter().map(|player| player.name).collect(),
+            keyboard: InputHandler::new(),
+            game_difficulty,
+            audio: Audio::new(sound_enabled, music_enabled),
+            broadcaster: None,
+            board_style: BoardStyle::default(),
+        }
+    }
+
+    /// Publishes every tick of the game to the spectators of `broadcaster`.
+    pub fn broadcast_to(&mut self, broadcaster: Broadcaster) {
+        self.broadcaster = Some(broadcaster);
+    }
+
+    /// Draws the board in `board_style` instead of the default one.
+    pub fn set_board_style(&mut self, board_style: BoardStyle) {
+        self.board_style = board_style;
+    }
+
+    /// Plays the game in `terminal` until it is decided or the host leaves
+    /// it. Clients that drop during the game lose their snake.
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>, host: &mut Host) -> NetworkExit {
+        self.audio.start_music();
+        host.start(&self.simulation);
+
+        loop {
+            host.poll();
+            for player in 1..self.names.len() {
+                if !host.is_connected(player) {
+                    self.simulation.eliminate(player);
+                }
+            }
+
+            // Only `Esc` leaves: the pause key would end the game for everyone
+            let current_direction = self.simulation.players()[0].snake().direction;
+            while poll(time::Duration::from_millis(0)).unwrap() {
+                let Event::Key(key_event) = read().unwrap() else {
+                    continue;
+                };
+                if key_event.code == KeyCode::Esc {
+                    publish_game_over(self.broadcaster.as_ref(), None);
+                    self.audio.stop_music();
+                    return NetworkExit::Left;
+                }
+
+                if let Some(direction) = Controls::Any.direction(key_event.code) {
+                    self.keyboard.push_turn(direction, current_direction);
+                }
+            }
+
+            let inputs: Vec<Option<Direction>> = std::iter::once(self.keyboard.next_turn())
+                .chain((1..self.names.len()).map(|player| host.next_turn(player)))
+                .collect();
+
+            let outcome = self.simulation.step(&inputs);
+            host.send_frame(&self.simulation);
+            let frame = Frame::new(&self.simulation, &host.latencies());
+            if let Some(broadcaster) = &self.broadcaster {
+                broadcaster.publish(&SpectatorMessage::Frame(SpectatorFrame::from_versus(
+                    &self.simulation,
+                    &self.names,
+                    false,
+                )));
+            }
+
+            match outcome {
+                VersusOutcome::Moved => {}
+                VersusOutcome::Ate => self.audio.play_eat(),
+                VersusOutcome::Died => self.audio.play_death(),
+                VersusOutcome::Over => {
+                    let summary = self.summary();
+                    host.finish(&summary);
+                    publish_game_over(self.broadcaster.as_ref(), Some(&summary));
+
+                    let shown = time::Instant::now();
+                    draw_screen(
+                        terminal,
+                        frame_screen(
+                            self.simulation.board(),
+                            self.board_style,
+                            &frame,
+                            &self.names,
+                            0,
+                        )
+                        .overlay(Overlay::message(&headline(&summary), Vec::new())),
+                    );
+
+                    if summary.winner == Some(0) {
+                        self.audio.play_fanfare();
+                    } else {
+                        self.audio.play_death();
+                    }
+
+                    self.audio.stop_music();
+                    thread::sleep(GAME_OVER_DELAY.saturating_sub(shown.elapsed()));
+
+                    return NetworkExit::Finished(summary);
+                }
+            }
+
+            draw_screen(
+                terminal,
+                frame_screen(
+                    self.simulation.board(),
+                    self.board_style,
+                    &frame,
+                    &self.names,
+                    0,
+                ),
+            );
+            thread::sleep(self.game_difficulty.tick_duration());
+        }
+    }
+
+    pub fn simulation(&self) -> &VersusSimulation {
+        &self.simulation
+    }
+
+    fn summary(&self) -> VersusSummary {
+        VersusSummary {
+            players: self
+                .names
+                .iter()
+                .zip(self.simulation.players())
+                .map(|(name, state)| PlayerSummary {
+                    name: name.clone(),
+                    score: state.score(),
+                    length: state.snake().body().len(),
+                    death_cause: state.death_cause(),
+                })
+                .collect(),
+            winner: self.simulation.winner(),
+            ticks: self.simulation.ticks(),
+            time_played: self.game_difficulty.tick_duration() * self.simulation.ticks() as u32,
+            seed: self.simulation.seed(),
+        }
+    }
+}
+
+/// Terminal front end of a client in a network game. Sends the player's
+/// turns to the host and draws the frames it sends back.
+pub struct ClientGame {
+    board: BoardConfig,
+    names: Vec<String>,
+    you: usize,
+    frame: Option<Frame>,
+    /// Last turn sent since the latest frame, so every turn is sent once.
+    sent_turn: Option<Direction>,
+    audio: Audio,
+    board_style: BoardStyle,
+}
+
+impl ClientGame {
+    /// A game as announced by the host's [`HostMessage::Start`].
+    pub fn new(
+        board: BoardConfig,
+        names: Vec<String>,
+        you: usize,
+        sound_enabled: bool,
+        music_enabled: bool,
+    ) -> Self {
+        Self {
+            board,
+            names,
+            you,
+            frame: None,
+            sent_turn: None,
+            audio: Audio::new(sound_enabled, music_enabled),
+            board_style: BoardStyle::default(),
+        }
+    }
+
+    /// Draws the board in `board_style` instead of the default one.
+    pub fn set_board_style(&mut self, board_style: BoardStyle) {
+        self.board_style = board_style;
+    }
+
+    /// Plays the game in `terminal` until the host says it is decided, the
+    /// player leaves it with `Esc` or the host is gone.
+    pub fn run<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        client: &mut Client,
+    ) -> NetworkExit {
+        self.audio.start_music();
+
+        loop {
+            for message in client.poll() {
+                match message {
+                    HostMessage::Frame(frame) => {
+                        self.play_sounds(&frame);
+                        draw_screen(
+                            terminal,
+                            frame_screen(
+                                &self.board,
+                                self.board_style,
+                                &frame,
+                                &self.names,
+                                self.you,
+                            ),
+                        );
+                        self.frame = Some(frame);
+                        self.sent_turn = None;
+                    }
+                    HostMessage::GameOver { summary } => {
+                        let shown = time::Instant::now();
+                        if let Some(frame) = &self.frame {
+                            draw_screen(
+                                terminal,
+                                frame_screen(
+                                    &self.board,
+                                    self.board_style,
+                                    frame,
+                                    &self.names,
+                                    self.you,
+                                )
+                                .overlay(Overlay::message(&headline(&summary), Vec::new())),
+                            );
+                        }
+
+                        if summary.winner == Some(self.you) {
+                            self.audio.play_fanfare();
+                        }
+
+                        self.audio.stop_music();
+                        thread::sleep(GAME_OVER_DELAY.saturating_sub(shown.elapsed()));
+
+                        return NetworkExit::Finished(summary);
+                    }
+                    _ => {}
+                }
+            }
+
+            if !client.is_connected() {
+                self.audio.stop_music();
+                return NetworkExit::Disconnected;
+            }
+
+            while poll(time::Duration::from_millis(0)).unwrap() {
+                let Event::Key(key_event) = read().unwrap() else {
+                    continue;
+                };
+                if key_event.code == KeyCode::Esc {
+                    self.audio.stop_music();
+                    return NetworkExit::Left;
+                }
+
+                let Some(direction) = Controls::Any.direction(key_event.code) else {
+                    continue;
+                };
+                let current_direction = self
+                    .frame
+                    .as_ref()
+                    .and_then(|frame| frame.snakes.get(self.you))
+                    .map(|snake| snake.direction);
+                // The host checks the turn, only skip sending it twice
+                if Some(direction) != self.sent_turn.or(current_direction) {
+                    client.send_turn(direction);
+                    self.sent_turn = Some(direction);
+                }
+            }
+
+            thread::sleep(CLIENT_POLL_INTERVAL);
+        }
+    }
+
+    /// Plays the sounds for what happened between the previous frame and
+    /// `frame`.
+    fn play_sounds(&self, frame: &Frame) {
+        let Some(previous) = &self.frame else {
+            return;
+        };
+
+        let changes = previous.snakes.iter().zip(&frame.snakes);
+        let died = changes
+            .clone()
+            .any(|(before, after)| before.alive && !after.alive);
+        let ate = changes
+            .clone()
+            .any(|(before, after)| after.score > before.score);
+        if died {
+            self.audio.play_death();
+        } else if ate {
+            self.audio.play_eat();
+        }
+    }
+}
+
+/// The living snakes of `frame` in their players' colors, with every
+/// player's score and latency in the side panel. `you` is the index of the
+/// player looking at the board.
+fn frame_screen(
+    board: &BoardConfig,
+    board_style: BoardStyle,
+    frame: &Frame,
+    names: &[String],
+    you: usize,
+) -> GameScreen {
+    let mut widget = BoardWidget::new(board, board_style);
+    let mut hud = Vec::new();
+    for (index, ((name, snake), color)) in names
+        .iter()
+        .zip(&frame.snakes)
+        .zip(PLAYER_COLORS)
+        .enumerate()
+    {
+        if snake.alive {
+            widget = widget.snake(&coordinates(&snake.body), snake.direction, color);
+        }
+
+        let name = if index == you {
+            format!("{} (you)", name)
+        } else {
+            name.clone()
+        };
+        hud.push(player_line(&name, color));
+        let mut details = format!("  Score: {}", snake.score);
+        if !snake.alive {
+            details.push_str(" (out)");
+        } else if let Some(latency) = snake.latency_ms {
+            details.push_str(&format!("  {} ms", latency));
+        }
+        hud.push(Spans::from(details));
+    }
+    hud.push(Spans::from(""));
+    hud.push(Spans::from("Esc: leave"));
+
+    let [x, y] = frame.food;
+    GameScreen::new(widget.food(Coordinate(x, y)), "Network Game", hud)
+}
+
+/// Cells of a snake sent as `[x, y]` pairs.
+fn coordinates(body: &[[i32; 2]]) -> Vec<Coordinate> {
+    body.iter().map(|&[x, y]| Coordinate(x, y)).collect()
+}
+
+/// Terminal front end of a spectator: draws the games published by a
+/// [`Broadcaster`](super::Broadcaster) read-only, with the same board renderer
+/// the players see, and keeps watching from one game to the next.
+#[derive(Debug, Default)]
+pub struct SpectatorView {
+    frame: Option<SpectatorFrame>,
+    /// How the last game ended, shown until the next one starts.
+    game_over: Option<String>,
+    board_style: BoardStyle,
+}
+
+impl SpectatorView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draws the board in `board_style` instead of the default one.
+    pub fn set_board_style(&mut self, board_style: BoardStyle) {
+        self.board_style = board_style;
+    }
+
+    /// Watches in `terminal` until the spectator presses `Esc` or `q`, which
+    /// returns [`NetworkExit::Left`], or the game is no longer published,
+    /// which returns [`NetworkExit::Disconnected`].
+    pub fn run<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        spectator: &mut Spectator,
+    ) -> NetworkExit {
+        let waiting = format!(
+            "Waiting for a game on {}...  (Esc: stop watching)",
+            spectator.game_addr()
+        );
+        terminal
+            .draw(|f| {
+                let text = Paragraph::new(waiting.as_str())
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true });
+                f.render_widget(text, f.size());
+            })
+            .unwrap();
+
+        loop {
+            let messages = spectator.poll();
+            if !messages.is_empty() {
+                for message in messages {
+                    match message {
+                        SpectatorMessage::Frame(frame) => {
+                            self.frame = Some(frame);
+                            self.game_over = None;
+                        }
+                        SpectatorMessage::GameOver { message } => self.game_over = Some(message),
+                        SpectatorMessage::Hello { .. } => {}
+                    }
+                }
+                if let Some(screen) = self.screen() {
+                    draw_screen(terminal, screen);
+                }
+            }
+
+            if !spectator.is_connected() {
+                return NetworkExit::Disconnected;
+            }
+
+            while poll(time::Duration::from_millis(0)).unwrap() {
+                if let Event::Key(key_event) = read().unwrap() {
+                    if matches!(key_event.code, KeyCode::Esc | KeyCode::Char('q')) {
+                        return NetworkExit::Left;
+                    }
+                }
+            }
+
+            thread::sleep(CLIENT_POLL_INTERVAL);
+        }
+    }
+
+    /// The latest frame with every player's name and score in the side
+    /// panel, and whether the game is paused or how it ended over the board.
+    fn screen(&self) -> Option<GameScreen> {
+        let frame = self.frame.as_ref()?;
+
+        // A single player's snake is yellow, like in their own terminal
+        let colors: Vec<Color> = if frame.snakes.len() == 1 {
+            vec![Color::LightYellow]
+        } else {
+            PLAYER_COLORS.to_vec()
+        };
+
+        let mut board = BoardWidget::new(&frame.board, self.board_style);
+        let mut hud = Vec::new();
+        for (snake, color) in frame.snakes.iter().zip(colors) {
+            if snake.alive {
+                board = board.snake(&coordinates(&snake.body), snake.direction, color);
+            }
+            hud.push(player_line(&snake.name, color));
+            hud.push(Spans::from(format!(
+                "  Score: {}{}",
+                snake.score,
+                if snake.alive { "" } else { " (out)" }
+            )));
+        }
+        hud.push(Spans::from(""));
+        hud.push(Spans::from("Esc/Q: stop watching"));
+
+        let [x, y] = frame.food;
+        let screen = GameScreen::new(board.food(Coordinate(x, y)), "Spectating", hud);
+        Some(match &self.game_over {
+            Some(message) => screen.overlay(Overlay::message(
+                message,
+                vec!["Waiting for the next game...".to_string()],
+            )),
+            None if frame.paused => screen.overlay(Overlay::message("Paused", Vec::new())),
+            None => screen,
+        })
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    io,
+    net::{SocketAddr, TcpListener, ToSocketAddrs},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    direction::Direction, input::InputHandler, versus::VersusSimulation, versus_game::VersusSummary,
+};
+
+use super::{
+    connection::Connection,
+    protocol::{ClientMessage, Frame, HostMessage, LobbyPlayer, PROTOCOL_VERSION},
+    MAX_CLIENTS,
+};
+
+/// How often every client is pinged to measure its latency.
+const PING_INTERVAL: Duration = Duration::from_secs(1);
+/// Clients that leave a ping unanswered for this long count as dropped.
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest a new connection may take to say hello.
+const HELLO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A client that joined the lobby.
+#[derive(Debug)]
+struct Peer {
+    connection: Connection,
+    name: String,
+    ready: bool,
+    latency: Option<Duration>,
+    /// Nonce and send time of the ping waiting for an answer.
+    ping: Option<(u64, Instant)>,
+    last_ping: Option<Instant>,
+    turns: InputHandler,
+    /// Direction of the player's snake after the last tick, to check turns
+    /// against.
+    direction: Direction,
+}
+
+impl Peer {
+    fn latency_ms(&self) -> Option<u64> {
+        self.latency.map(|latency| latency.as_millis() as u64)
+    }
+}
+
+/// Hosts a network game: keeps the lobby of clients that joined over TCP,
+/// and while a game runs, collects their turns and streams the state of the
+/// authoritative [`VersusSimulation`] to them.
+///
+/// The host is always the first player. Nothing blocks; call [`Host::poll`]
+/// regularly, e.g. on every tick or redraw, to accept clients, read their
+/// messages and notice the ones that dropped. Clients that drop while in the
+/// lobby are removed from it; clients that drop during a game stay in the
+/// player list until the game is over, see [`Host::is_connected`].
+#[derive(Debug)]
+pub struct Host {
+    listener: TcpListener,
+    name: String,
+    /// Connections that did not say hello yet, with when they were accepted.
+    pending: Vec<(Connection, Instant)>,
+    peers: Vec<Peer>,
+    in_game: bool,
+    next_nonce: u64,
+    lobby_changed: bool,
+}
+
+impl Host {
+    /// Listens for clients on `address`; the host plays as `name`.
+    pub fn bind<A: ToSocketAddrs>(address: A, name: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            name: name.to_string(),
+            pending: Vec::new(),
+            peers: Vec::new(),
+            in_game: false,
+            next_nonce: 0,
+            lobby_changed: false,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts new clients, handles every message that arrived and pings the
+    /// clients that are due. Sends the lobby to every client when it changed.
+    pub fn poll(&mut self) {
+        self.accept();
+        self.greet();
+
+        let in_game = self.in_game;
+        let mut lobby_changed = false;
+        for peer in &mut self.peers {
+            while let Some((message, received)) = peer.connection.try_recv_timed() {
+                match message {
+                    ClientMessage::Ready { ready } if !in_game => {
+                        lobby_changed |= peer.ready != ready;
+                        peer.ready = ready;
+                    }
+                    ClientMessage::Turn { direction } if in_game => {
+                        peer.turns.push_turn(direction, peer.direction);
+                    }
+                    ClientMessage::Pong { nonce } => {
+                        if let Some((sent_nonce, sent)) = peer.ping {
+                            if nonce == sent_nonce {
+                                peer.latency = Some(received.duration_since(sent));
+                                peer.ping = None;
+                                lobby_changed = true;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some((_, sent)) = peer.ping {
+                if sent.elapsed() >= PING_TIMEOUT {
+                    peer.connection.close();
+                }
+            } else if peer
+                .last_ping
+                .is_none_or(|last_ping| last_ping.elapsed() >= PING_INTERVAL)
+            {
+                let nonce = self.next_nonce;
+                self.next_nonce += 1;
+                peer.connection.send(&HostMessage::Ping { nonce });
+                peer.ping = Some((nonce, Instant::now()));
+                peer.last_ping = Some(Instant::now());
+            }
+        }
+
+        if !self.in_game {
+            let count = self.peers.len();
+            self.peers.retain(|peer| !peer.connection.is_closed());
+            lobby_changed |= self.peers.len() != count;
+        }
+
+        if lobby_changed || self.lobby_changed {
+            self.lobby_changed = false;
+            if !self.in_game {
+                self.send_lobby();
+            }
+        }
+    }
+
+    fn accept(&mut self) {
+        // Stop on errors too, e.g. a client that hung up before it was accepted
+        while let Ok((stream, _)) = self.listener.accept() {
+            if let Ok(connection) = Connection::new(stream) {
+                self.pending.push((connection, Instant::now()));
+            }
+        }
+    }
+
+    /// Lets pending connections that said hello join the lobby, or turns
+    /// them away.
+    fn greet(&mut self) {
+        let mut index = 0;
+        while index < self.pending.len() {
+            let (connection, accepted) = &mut self.pending[index];
+            let hello = connection.try_recv();
+            if hello.is_none() && !connection.is_closed() && accepted.elapsed() < HELLO_TIMEOUT {
+                index += 1;
+                continue;
+            }
+
+            let (mut connection, _) = self.pending.remove(index);
+            let Some(ClientMessage::Hello { version, name }) = hello else {
+                continue;
+            };
+
+            let rejection = if version != PROTOCOL_VERSION {
+                Some(format!(
+                    "protocol version {} is not supported (expected {})",
+                    version, PROTOCOL_VERSION
+                ))
+            } else if self.in_game {
+                Some("a game is running, try again when it is over".to_string())
+            } else if self.peers.len() >= MAX_CLIENTS {
+                Some("the lobby is full".to_string())
+            } else {
+                None
+            };
+            if let Some(reason) = rejection {
+                connection.send(&HostMessage::Rejected { reason });
+                continue;
+            }
+
+            connection.send(&HostMessage::Welcome);
+            let name = name.trim();
+            self.peers.push(Peer {
+                name: if name.is_empty() {
+                    connection.peer().ip().to_string()
+                } else {
+                    name.to_string()
+                },
+                connection,
+                ready: false,
+                latency: None,
+                ping: None,
+                last_ping: None,
+                turns: InputHandler::new(),
+                direction: Direction::Right,
+            });
+            self.lobby_changed = true;
+        }
+    }
+
+    fn send_lobby(&mut self) {
+        let players = self.lobby();
+        for (index, peer) in self.peers.iter_mut().enumerate() {
+            peer.connection.send(&HostMessage::Lobby {
+                players: players.clone(),
+                you: index + 1,
+            });
+        }
+    }
+
+    /// Everyone in the lobby, the host first.
+    pub fn lobby(&self) -> Vec<LobbyPlayer> {
+        let host = LobbyPlayer {
+            name: self.name.clone(),
+            host: true,
+            ready: true,
+            latency_ms: None,
+        };
+        let clients = self.peers.iter().map(|peer| LobbyPlayer {
+            name: peer.name.clone(),
+            host: false,
+            ready: peer.ready,
+            latency_ms: peer.latency_ms(),
+        });
+
+        std::iter::once(host).chain(clients).collect()
+    }
+
+    /// Number of players in the lobby, the host included.
+    pub fn player_count(&self) -> usize {
+        1 + self.peers.len()
+    }
+
+    /// Whether a game can start: at least one client joined and every
+    /// client is ready.
+    pub fn can_start(&self) -> bool {
+        !self.in_game && !self.peers.is_empty() && self.peers.iter().all(|peer| peer.ready)
+    }
+
+    pub fn in_game(&self) -> bool {
+        self.in_game
+    }
+
+    /// Tells every client that a game of `simulation` starts, with one snake
+    /// for each player in lobby order, and sends them its first frame.
+    pub fn start(&mut self, simulation: &VersusSimulation) {
+        assert_eq!(
+            simulation.players().len(),
+            self.player_count(),
+            "one snake for each player in the lobby"
+        );
+
+        let players: Vec<String> = self.lobby().into_iter().map(|player| player.name).collect();
+        for (index, peer) in self.peers.iter_mut().enumerate() {
+            peer.turns = InputHandler::new();
+            peer.connection.send(&HostMessage::Start {
+                board: *simulation.board(),
+                players: players.clone(),
+                you: index + 1,
+            });
+        }
+        self.in_game = true;
+
+        self.send_frame(simulation);
+    }
+
+    /// Whether `player` is still connected. The host always is.
+    pub fn is_connected(&self, player: usize) -> bool {
+        player == 0 || !self.peers[player - 1].connection.is_closed()
+    }
+
+    /// Takes the turn `player` plays on this tick, if any. The host's own
+    /// turns come from the keyboard instead.
+    pub fn next_turn(&mut self, player: usize) -> Option<Direction> {
+        match player {
+            0 => None,
+            _ => self.peers[player - 1].turns.next_turn(),
+        }
+    }
+
+    /// Round trip time to each player in milliseconds, `None` for the host
+    /// and players not measured yet.
+    pub fn latencies(&self) -> Vec<Option<u64>> {
+        std::iter::once(None)
+            .chain(self.peers.iter().map(Peer::latency_ms))
+            .collect()
+    }
+
+    /// Sends the state of `simulation` after a tick to every client.
+    pub fn send_frame(&mut self, simulation: &VersusSimulation) {
+        let frame = HostMessage::Frame(Frame::new(simulation, &self.latencies()));
+        for (peer, player) in self.peers.iter_mut().zip(&simulation.players()[1..]) {
+            peer.direction = player.snake().direction;
+            peer.connection.send(&frame);
+        }
+    }
+
+    /// Tells every client how the game ended and goes back to the lobby,
+    /// where every client has to ready up again for the next game.
+    pub fn finish(&mut self, summary: &VersusSummary) {
+        let game_over = HostMessage::GameOver {
+            summary: summary.clone(),
+        };
+        for peer in &mut self.peers {
+            peer.connection.send(&game_over);
+            peer.ready = false;
+        }
+
+        self.in_game = false;
+        self.peers.retain(|peer| !peer.connection.is_closed());
+        self.lobby_changed = true;
+    }
+}
@@ -0,0 +1,51 @@
+mod client;
+mod connection;
+mod game;
+mod host;
+pub mod protocol;
+mod spectate;
+
+pub use client::Client;
+pub use game::{ClientGame, HostGame, NetworkExit, SpectatorView};
+pub use host::Host;
+pub use spectate::{Broadcaster, Spectator};
+
+use std::{fmt, io};
+
+use crate::versus::MAX_PLAYERS;
+
+/// Port a network game is hosted on unless another one is given.
+pub const DEFAULT_PORT: u16 = 7879;
+
+/// Most clients that can join a host, who plays as well.
+pub const MAX_CLIENTS: usize = MAX_PLAYERS - 1;
+
+#[derive(Debug)]
+pub enum NetError {
+    Io(io::Error),
+    /// The host turned the client away, for the given reason.
+    Rejected(String),
+    /// The other side hung up.
+    Disconnected,
+    /// The other side did not answer in time.
+    TimedOut,
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Io(error) => write!(f, "network error: {}", error),
+            NetError::Rejected(reason) => write!(f, "the host turned you away: {}", reason),
+            NetError::Disconnected => write!(f, "the connection was closed"),
+            NetError::TimedOut => write!(f, "the other side did not answer in time"),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+impl From<io::Error> for NetError {
+    fn from(error: io::Error) -> Self {
+        NetError::Io(error)
+    }
+}
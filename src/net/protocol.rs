@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    board::BoardConfig, coordinate::Coordinate, direction::Direction, simulation::Simulation,
+    versus::VersusSimulation, versus_game::VersusSummary,
+};
+
+/// Version of the protocol spoken between the host of a network game and
+/// its clients. Clients speaking another version are turned away.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Message sent by a client to the host, as one line of JSON, e.g.
+///
+/// ```text
+/// {"type":"hello","version":1,"name":"Ada"}
+/// {"type":"turn","direction":"Up"}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// First message of every client, asking to join the lobby.
+    Hello { version: u32, name: String },
+    /// Whether the player is ready for the next game.
+    Ready { ready: bool },
+    /// The player turned their snake. Only sent when the direction changes.
+    Turn { direction: Direction },
+    /// Answer to [`HostMessage::Ping`], echoing its nonce.
+    Pong { nonce: u64 },
+}
+
+/// Message sent by the host to a client, as one line of JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HostMessage {
+    /// The client joined the lobby.
+    Welcome,
+    /// The client cannot join, e.g. because the lobby is full. The host
+    /// closes the connection right after.
+    Rejected { reason: String },
+    /// Everyone in the lobby, in player order. `you` is the receiving
+    /// client's index in `players`.
+    Lobby {
+        players: Vec<LobbyPlayer>,
+        you: usize,
+    },
+    /// A game starts on `board`, with one snake for each of `players`.
+    Start {
+        board: BoardConfig,
+        players: Vec<String>,
+        you: usize,
+    },
+    /// State of the running game after a tick.
+    Frame(Frame),
+    /// The game is decided.
+    GameOver { summary: VersusSummary },
+    /// Asks the client to answer with [`ClientMessage::Pong`] right away,
+    /// to measure the round trip time.
+    Ping { nonce: u64 },
+}
+
+/// One player waiting in the lobby.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LobbyPlayer {
+    pub name: String,
+    /// The host is always the first player and always ready.
+    pub host: bool,
+    pub ready: bool,
+    /// Round trip time to the player in milliseconds, once measured.
+    pub latency_ms: Option<u64>,
+}
+
+/// State of every snake of a network game after a tick, in player order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    pub tick: u64,
+    pub food: [i32; 2],
+    pub snakes: Vec<SnakeState>,
+}
+
+/// One snake in a [`Frame`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnakeState {
+    /// Cells from head to tail as `[x, y]` pairs.
+    pub body: Vec<[i32; 2]>,
+    pub direction: Direction,
+    pub score: u32,
+    pub alive: bool,
+    /// Round trip time to the player in milliseconds, once measured.
+    pub latency_ms: Option<u64>,
+}
+
+impl Frame {
+    /// Captures the state of `simulation`, with the round trip time to
+    /// each player, `None` for the host.
+    pub fn new(simulation: &VersusSimulation, latencies: &[Option<u64>]) -> Self {
+        Self {
+            tick: simulation.ticks(),
+            food: cell(simulation.food().position),
+            snakes: simulation
+                .players()
+                .iter()
+                .enumerate()
+                .map(|(index, player)| SnakeState {
+                    body: player.snake().body().iter().copied().map(cell).collect(),
+                    direction: player.snake().direction,
+                    score: player.score(),
+                    alive: player.is_alive(),
+                    latency_ms: latencies.get(index).copied().flatten(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn cell(Coordinate(x, y): Coordinate) -> [i32; 2] {
+    [x, y]
+}
+
+/// Version of the messages published to spectators. Spectators expecting
+/// another version stop watching right after the hello.
+pub const SPECTATOR_PROTOCOL_VERSION: u32 = 1;
+
+/// Message published to spectators watching a game, as one line of JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpectatorMessage {
+    /// First message to every spectator, before any frame.
+    Hello { version: u32 },
+    /// State of the game after a tick.
+    Frame(SpectatorFrame),
+    /// The game came to an end, summed up by `message`, e.g. "Ada wins!".
+    GameOver { message: String },
+}
+
+/// What a spectator sees of a game after a tick: the board and every snake
+/// with its player's name and score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpectatorFrame {
+    pub board: BoardConfig,
+    pub tick: u64,
+    pub food: [i32; 2],
+    /// One snake for a single-player game, or every snake of a versus game
+    /// in player order.
+    pub snakes: Vec<SpectatedSnake>,
+    /// Whether the game is paused.
+    pub paused: bool,
+}
+
+/// One snake in a [`SpectatorFrame`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpectatedSnake {
+    pub name: String,
+    /// Cells from head to tail as `[x, y]` pairs.
+    pub body: Vec<[i32; 2]>,
+    /// Where the head points.
+    pub direction: Direction,
+    pub score: u32,
+    pub alive: bool,
+}
+
+impl SpectatorFrame {
+    /// Captures a single-player game, played by `name`.
+    pub fn from_simulation(simulation: &Simulation, name: &str, paused: bool) -> Self {
+        Self {
+            board: *simulation.board(),
+            tick: simulation.ticks(),
+            food: cell(simulation.food().position),
+            snakes: vec![SpectatedSnake {
+                name: name.to_string(),
+                body: simulation
+                    .snake()
+                    .body()
+                    .iter()
+                    .copied()
+                    .map(cell)
+                    .collect(),
+                direction: simulation.snake().direction,
+                score: simulation.score(),
+                alive: simulation.death_cause().is_none(),
+            }],
+            paused,
+        }
+    }
+
+    /// Captures a versus game, with the name of each player in order.
+    pub fn from_versus(simulation: &VersusSimulation, names: &[String], paused: bool) -> Self {
+        Self {
+            board: *simulation.board(),
+            tick: simulation.ticks(),
+            food: cell(simulation.food().position),
+            snakes: simulation
+                .players()
+                .iter()
+                .zip(names)
+                .map(|(player, name)| SpectatedSnake {
+                    name: name.clone(),
+                    body: player.snake().body().iter().copied().map(cell).collect(),
+                    direction: player.snake().direction,
+                    score: player.score(),
+                    alive: player.is_alive(),
+                })
+                .collect(),
+            paused,
+        }
+    }
+}
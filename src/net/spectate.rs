@@ -0,0 +1,159 @@
+use std::{
+    io::{self, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, SyncSender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use super::{
+    connection::Connection,
+    protocol::{SpectatorMessage, SPECTATOR_PROTOCOL_VERSION},
+};
+
+/// Longest a message may take to reach a spectator before they are dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Messages waiting for a spectator. A spectator that falls this far behind
+/// is dropped, so a slow spectator cannot hold up the game.
+const SPECTATOR_QUEUE: usize = 64;
+
+/// How long a new spectator waits for the game's hello.
+const HELLO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Publishes the running game to spectators connecting over TCP.
+///
+/// Spectators are accepted on a background thread, so they can attach at
+/// any time, even between games. Each spectator has its own writer thread
+/// fed through a bounded queue, so publishing never waits on the network;
+/// spectators are dropped as soon as a message cannot be sent to them or
+/// their queue is full. Clones share the same spectators, so one
+/// broadcaster can be handed to every game of a session.
+#[derive(Debug, Clone)]
+pub struct Broadcaster {
+    local_addr: SocketAddr,
+    spectators: Arc<Mutex<Vec<SyncSender<Arc<str>>>>>,
+}
+
+impl Broadcaster {
+    pub fn bind<A: ToSocketAddrs>(address: A) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let local_addr = listener.local_addr()?;
+        let spectators = Arc::new(Mutex::new(Vec::new()));
+
+        let hello: Arc<str> = serde_json::to_string(&SpectatorMessage::Hello {
+            version: SPECTATOR_PROTOCOL_VERSION,
+        })?
+        .into();
+        let accepted = spectators.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                if stream.set_nodelay(true).is_err()
+                    || stream.set_write_timeout(Some(WRITE_TIMEOUT)).is_err()
+                {
+                    continue;
+                }
+
+                let (sender, lines) = mpsc::sync_channel(SPECTATOR_QUEUE);
+                let _ = sender.try_send(hello.clone());
+                thread::spawn(move || write_lines(stream, lines));
+                accepted.lock().unwrap().push(sender);
+            }
+        });
+
+        Ok(Self {
+            local_addr,
+            spectators,
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Number of spectators attached. Spectators that left are only noticed
+    /// when the next message is published.
+    pub fn spectator_count(&self) -> usize {
+        self.spectators.lock().unwrap().len()
+    }
+
+    /// Queues `message` for every spectator, dropping the ones that left or
+    /// fell behind. Never waits for the spectators to receive it.
+    pub fn publish(&self, message: &SpectatorMessage) {
+        let Ok(line) = serde_json::to_string(message) else {
+            return;
+        };
+        let line: Arc<str> = line.into();
+
+        self.spectators
+            .lock()
+            .unwrap()
+            .retain(|sender| sender.try_send(line.clone()).is_ok());
+    }
+}
+
+/// Writes every queued line to a spectator until the queue is dropped or
+/// the spectator is gone, which disconnects the queue in turn.
+fn write_lines(mut stream: TcpStream, lines: mpsc::Receiver<Arc<str>>) {
+    for line in lines {
+        if writeln!(stream, "{}", line).is_err() {
+            break;
+        }
+    }
+}
+
+/// Watches a game published by a [`Broadcaster`].
+#[derive(Debug)]
+pub struct Spectator {
+    connection: Connection,
+}
+
+impl Spectator {
+    /// Connects to the game published on `address`, failing when it does
+    /// not greet with the [`SPECTATOR_PROTOCOL_VERSION`] this build speaks.
+    pub fn connect<A: ToSocketAddrs>(address: A) -> io::Result<Self> {
+        let mut connection = Connection::new(TcpStream::connect(address)?)?;
+
+        match connection.recv_timeout(HELLO_TIMEOUT) {
+            Some(SpectatorMessage::Hello { version }) if version == SPECTATOR_PROTOCOL_VERSION => {
+                Ok(Self { connection })
+            }
+            Some(SpectatorMessage::Hello { version }) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "spectator protocol version {} is not supported (expected {})",
+                    version, SPECTATOR_PROTOCOL_VERSION
+                ),
+            )),
+            Some(_) | None if connection.is_closed() => {
+                Err(io::ErrorKind::ConnectionAborted.into())
+            }
+            Some(_) | None => Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "the game did not say hello",
+            )),
+        }
+    }
+
+    pub fn game_addr(&self) -> SocketAddr {
+        self.connection.peer()
+    }
+
+    /// Takes every message published since the last call, in order.
+    pub fn poll(&mut self) -> Vec<SpectatorMessage> {
+        let mut messages = Vec::new();
+        while let Some(message) = self.connection.try_recv() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    /// Whether the game is still being published.
+    pub fn is_connected(&self) -> bool {
+        !self.connection.is_closed()
+    }
+}
@@ -0,0 +1,38 @@
+use std::{env, path::PathBuf};
+
+/// Directory where the game keeps its files (replays, high scores, ...).
+///
+/// `SNAKE_DATA_DIR` takes precedence, followed by the platform's usual data
+/// directory. Falls back to `.snake` in the working directory.
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("SNAKE_DATA_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    if let Some(dir) = env::var_os("XDG_DATA_HOME") {
+        return PathBuf::from(dir).join("snake");
+    }
+
+    if let Some(dir) = env::var_os("APPDATA") {
+        return PathBuf::from(dir).join("snake");
+    }
+
+    if let Some(home) = env::var_os("HOME") {
+        return PathBuf::from(home)
+            .join(".local")
+            .join("share")
+            .join("snake");
+    }
+
+    PathBuf::from(".snake")
+}
+
+/// Directory where finished games are saved as replay files.
+pub fn replay_dir() -> PathBuf {
+    data_dir().join("replays")
+}
+
+/// File holding the high score tables.
+pub fn high_scores_path() -> PathBuf {
+    data_dir().join("high_scores.json")
+}
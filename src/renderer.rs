@@ -0,0 +1,376 @@
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    queue,
+    style::{
+        Attribute, Attributes, Color, Print, ResetColor, SetAttribute, SetAttributes,
+        SetBackgroundColor, SetForegroundColor,
+    },
+    terminal::{self, Clear, ClearType},
+};
+use std::io::{self, Stdout, Write};
+use tui::{backend::Backend, buffer, layout::Rect, style};
+
+/// Size assumed when the size of the terminal cannot be read.
+const FALLBACK_SIZE: (u16, u16) = (80, 24);
+
+/// One character cell of the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub foreground: Color,
+    pub background: Color,
+    /// Bold, underlined and the like.
+    pub attributes: Attributes,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: ' ',
+            foreground: Color::Reset,
+            background: Color::Reset,
+            attributes: Attributes::default(),
+        }
+    }
+}
+
+/// Everything on the screen for one frame, drawn cell by cell and then
+/// handed to a [`Renderer`] to show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameBuffer {
+    width: u16,
+    height: u16,
+    cells: Vec<Cell>,
+    /// Where the cursor is shown, hidden when `None`.
+    cursor: Option<(u16, u16)>,
+}
+
+impl FrameBuffer {
+    /// A blank frame of `width` columns and `height` rows.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::default(); width as usize * height as usize],
+            cursor: None,
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// The cell at column `x` of row `y`, or `None` outside the frame.
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        self.index(x, y).map(|index| &self.cells[index])
+    }
+
+    /// Replaces the cell at column `x` of row `y`. Cells outside the frame
+    /// are ignored, so callers need not clip what they draw.
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if let Some(index) = self.index(x, y) {
+            self.cells[index] = cell;
+        }
+    }
+
+    /// Writes `text` from column `x` of row `y` on, one character per cell,
+    /// cut off at the right edge of the frame.
+    pub fn print(&mut self, x: u16, y: u16, text: &str, foreground: Color, background: Color) {
+        for (offset, symbol) in text.chars().enumerate() {
+            let Some(x) = x.checked_add(offset as u16).filter(|&x| x < self.width) else {
+                break;
+            };
+            self.set(
+                x,
+                y,
+                Cell {
+                    symbol,
+                    foreground,
+                    background,
+                    ..Cell::default()
+                },
+            );
+        }
+    }
+
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// Shows the cursor at column `x` of row `y` with `Some((x, y))`, or
+    /// hides it with `None`.
+    pub fn set_cursor(&mut self, cursor: Option<(u16, u16)>) {
+        self.cursor = cursor;
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y as usize * self.width as usize + x as usize)
+    }
+}
+
+/// Shows frames on a screen.
+pub trait Renderer {
+    /// Size of the screen as columns and rows, i.e. of the frames to draw.
+    fn size(&self) -> (u16, u16);
+
+    /// Shows `frame` in place of the previous one.
+    fn draw(&mut self, frame: &FrameBuffer) -> io::Result<()>;
+
+    /// Forgets what is on the screen, so the next frame is drawn in full,
+    /// e.g. after something else drew over it.
+    fn invalidate(&mut self);
+
+    /// Draws a frame the size of the screen with `draw` and shows it.
+    fn render(&mut self, draw: &dyn Fn(&mut FrameBuffer)) -> io::Result<()> {
+        let (columns, rows) = self.size();
+        let mut frame = FrameBuffer::new(columns, rows);
+        draw(&mut frame);
+        self.draw(&frame)
+    }
+}
+
+/// Renders to a terminal with crossterm, sending only the cells that
+/// changed since the previous frame.
+///
+/// The escape sequences of a frame are queued and written with a single
+/// flush, and cursor moves and colors are only sent when they differ from
+/// the previous cell's, so a frame in which the snake moved costs a few
+/// dozen bytes instead of a redraw of the whole screen.
+#[derive(Debug)]
+pub struct CrosstermRenderer<W: Write> {
+    writer: W,
+    /// The frame on the screen, unless it has to be drawn from scratch.
+    previous: Option<FrameBuffer>,
+}
+
+impl CrosstermRenderer<Stdout> {
+    /// Renders to the terminal on standard output.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> CrosstermRenderer<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            previous: None,
+        }
+    }
+
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<W: Write> Renderer for CrosstermRenderer<W> {
+    fn size(&self) -> (u16, u16) {
+        terminal::size().unwrap_or(FALLBACK_SIZE)
+    }
+
+    fn draw(&mut self, frame: &FrameBuffer) -> io::Result<()> {
+        // A new screen, or one that was resized, starts out blank with the
+        // cursor in an unknown state
+        let (previous, cursor_known) = match self.previous.take() {
+            Some(previous) if previous.width == frame.width && previous.height == frame.height => {
+                (previous, true)
+            }
+            _ => {
+                queue!(self.writer, ResetColor, Clear(ClearType::All))?;
+                (FrameBuffer::new(frame.width, frame.height), false)
+            }
+        };
+
+        let mut cursor = None;
+        let mut style = None;
+        for y in 0..frame.height {
+            for x in 0..frame.width {
+                let cell = frame.get(x, y).unwrap();
+                if previous.get(x, y) == Some(cell) {
+                    continue;
+                }
+
+                if cursor != Some((x, y)) {
+                    queue!(self.writer, MoveTo(x, y))?;
+                }
+                // Every frame starts out without attributes, and resetting
+                // them resets the colors too
+                let attributes =
+                    style.map_or(Attributes::default(), |(_, _, attributes)| attributes);
+                if attributes != cell.attributes {
+                    queue!(
+                        self.writer,
+                        SetAttribute(Attribute::Reset),
+                        SetAttributes(cell.attributes)
+                    )?;
+                    style = None;
+                }
+                if style != Some((cell.foreground, cell.background, cell.attributes)) {
+                    queue!(
+                        self.writer,
+                        SetForegroundColor(cell.foreground),
+                        SetBackgroundColor(cell.background)
+                    )?;
+                    style = Some((cell.foreground, cell.background, cell.attributes));
+                }
+                queue!(self.writer, Print(cell.symbol))?;
+                cursor = Some((x + 1, y));
+            }
+        }
+
+        if style.is_some() {
+            queue!(self.writer, ResetColor)?;
+        }
+
+        match frame.cursor {
+            Some((x, y)) => {
+                if cursor.is_some() || previous.cursor != frame.cursor || !cursor_known {
+                    queue!(self.writer, MoveTo(x, y))?;
+                }
+                if previous.cursor.is_none() || !cursor_known {
+                    queue!(self.writer, Show)?;
+                }
+            }
+            None if previous.cursor.is_some() || !cursor_known => queue!(self.writer, Hide)?,
+            None => {}
+        }
+        self.writer.flush()?;
+
+        self.previous = Some(frame.clone());
+        Ok(())
+    }
+
+    fn invalidate(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// A `tui` backend drawing through a [`Renderer`].
+///
+/// `tui` hands over the cells that changed, which are collected in a
+/// [`FrameBuffer`] and shown by the renderer when the frame is complete, so
+/// widgets get the renderer's single flush per frame.
+#[derive(Debug)]
+pub struct RendererBackend<R: Renderer> {
+    renderer: R,
+    frame: FrameBuffer,
+}
+
+impl<R: Renderer> RendererBackend<R> {
+    pub fn new(renderer: R) -> Self {
+        let (columns, rows) = renderer.size();
+        Self {
+            renderer,
+            frame: FrameBuffer::new(columns, rows),
+        }
+    }
+
+    pub fn renderer_mut(&mut self) -> &mut R {
+        &mut self.renderer
+    }
+}
+
+impl<R: Renderer> Backend for RendererBackend<R> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a buffer::Cell)>,
+    {
+        for (x, y, cell) in content {
+            self.frame.set(
+                x,
+                y,
+                Cell {
+                    symbol: cell.symbol.chars().next().unwrap_or(' '),
+                    foreground: color(cell.fg),
+                    background: color(cell.bg),
+                    attributes: attributes(cell.modifier),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.frame.set_cursor(None);
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        let position = self.frame.cursor().unwrap_or_default();
+        self.frame.set_cursor(Some(position));
+        Ok(())
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        Ok(self.frame.cursor().unwrap_or_default())
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.frame.set_cursor(Some((x, y)));
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        let (columns, rows) = self.renderer.size();
+        self.frame = FrameBuffer::new(columns, rows);
+        self.renderer.invalidate();
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        let (columns, rows) = self.renderer.size();
+        Ok(Rect::new(0, 0, columns, rows))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.renderer.draw(&self.frame)
+    }
+}
+
+fn color(color: style::Color) -> Color {
+    match color {
+        style::Color::Reset => Color::Reset,
+        style::Color::Black => Color::Black,
+        style::Color::Red => Color::DarkRed,
+        style::Color::Green => Color::DarkGreen,
+        style::Color::Yellow => Color::DarkYellow,
+        style::Color::Blue => Color::DarkBlue,
+        style::Color::Magenta => Color::DarkMagenta,
+        style::Color::Cyan => Color::DarkCyan,
+        style::Color::Gray => Color::Grey,
+        style::Color::DarkGray => Color::DarkGrey,
+        style::Color::LightRed => Color::Red,
+        style::Color::LightGreen => Color::Green,
+        style::Color::LightYellow => Color::Yellow,
+        style::Color::LightBlue => Color::Blue,
+        style::Color::LightMagenta => Color::Magenta,
+        style::Color::LightCyan => Color::Cyan,
+        style::Color::White => Color::White,
+        style::Color::Rgb(r, g, b) => Color::Rgb { r, g, b },
+        style::Color::Indexed(index) => Color::AnsiValue(index),
+    }
+}
+
+fn attributes(modifier: style::Modifier) -> Attributes {
+    [
+        (style::Modifier::BOLD, Attribute::Bold),
+        (style::Modifier::DIM, Attribute::Dim),
+        (style::Modifier::ITALIC, Attribute::Italic),
+        (style::Modifier::UNDERLINED, Attribute::Underlined),
+        (style::Modifier::SLOW_BLINK, Attribute::SlowBlink),
+        (style::Modifier::RAPID_BLINK, Attribute::RapidBlink),
+        (style::Modifier::REVERSED, Attribute::Reverse),
+        (style::Modifier::HIDDEN, Attribute::Hidden),
+        (style::Modifier::CROSSED_OUT, Attribute::CrossedOut),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| modifier.contains(*flag))
+    .fold(Attributes::default(), |mut attributes, (_, attribute)| {
+        attributes.set(attribute);
+        attributes
+    })
+}
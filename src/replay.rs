@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    board::BoardConfig,
+    direction::Direction,
+    game::GameDifficulty,
+    simulation::{DeathCause, Simulation, StepOutcome},
+};
+
+/// Version of the replay file format written by this build.
+pub const REPLAY_VERSION: u32 = 2;
+
+/// Extension of replay files.
+pub const REPLAY_EXTENSION: &str = "jsonl";
+
+/// First line of a replay file, describing how to set up the game and how it ended.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub version: u32,
+    pub seed: u64,
+    pub board: BoardConfig,
+    pub difficulty: GameDifficulty,
+    pub score: u32,
+    pub ticks: u64,
+    pub death_cause: Option<DeathCause>,
+    /// Whether the snake filled the whole board.
+    pub won: bool,
+    /// Seconds since the Unix epoch when the game ended.
+    pub recorded_at: u64,
+}
+
+impl ReplayHeader {
+    /// Reads only the header line of a replay file, e.g. to list saved replays.
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let mut line = String::new();
+        BufReader::new(fs::File::open(path)?).read_line(&mut line)?;
+        if line.trim().is_empty() {
+            return Err(ReplayError::Empty);
+        }
+
+        parse_header(&line)
+    }
+}
+
+/// A recorded game: everything needed to reproduce it tick by tick.
+///
+/// Replays are stored as JSON lines. The first line is the [`ReplayHeader`],
+/// followed by one line per tick holding that tick's input (`null` when no
+/// direction was given), e.g.
+///
+/// ```text
+/// {"version":2,"seed":42,"board":{"width":40,"height":20,"topology":"Walled"},...}
+/// null
+/// "Up"
+/// null
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    pub header: ReplayHeader,
+    pub inputs: Vec<Option<Direction>>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// The file has no header line.
+    Empty,
+    /// The file was written by a newer or older, incompatible format version.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io(error) => write!(f, "could not access replay: {}", error),
+            ReplayError::Json(error) => write!(f, "malformed replay: {}", error),
+            ReplayError::Empty => write!(f, "replay file is empty"),
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "replay version {} is not supported (expected {})",
+                version, REPLAY_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(error: io::Error) -> Self {
+        ReplayError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for ReplayError {
+    fn from(error: serde_json::Error) -> Self {
+        ReplayError::Json(error)
+    }
+}
+
+impl Replay {
+    /// Captures a (usually finished) simulation as a replay.
+    pub fn from_simulation(simulation: &Simulation, difficulty: GameDifficulty) -> Self {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            header: ReplayHeader {
+                version: REPLAY_VERSION,
+                seed: simulation.seed(),
+                board: *simulation.board(),
+                difficulty,
+                score: simulation.score(),
+                ticks: simulation.ticks(),
+                death_cause: simulation.death_cause(),
+                won: simulation.is_won(),
+                recorded_at,
+            },
+            inputs: simulation.inputs().to_vec(),
+        }
+    }
+
+    /// A fresh simulation set up exactly like the recorded game was.
+    pub fn new_simulation(&self) -> Simulation {
+        Simulation::new(self.header.board, self.header.seed)
+    }
+
+    /// Plays every recorded input and returns the resulting simulation.
+    pub fn play_to_end(&self) -> Simulation {
+        let mut simulation = self.new_simulation();
+        for &input in &self.inputs {
+            simulation.step(input);
+        }
+        simulation
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), ReplayError> {
+        serde_json::to_writer(&mut writer, &self.header)?;
+        writeln!(writer)?;
+
+        for input in &self.inputs {
+            serde_json::to_writer(&mut writer, input)?;
+            writeln!(writer)?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn read_from<R: BufRead>(reader: R) -> Result<Self, ReplayError> {
+        let mut lines = reader.lines();
+
+        let header = parse_header(&lines.next().ok_or(ReplayError::Empty)??)?;
+
+        let mut inputs = Vec::new();
+        for line in lines {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            inputs.push(serde_json::from_str(&line)?);
+        }
+
+        Ok(Self { header, inputs })
+    }
+
+    /// Writes the replay into `directory` under a unique name and returns its path.
+    pub fn save(&self, directory: &Path) -> Result<PathBuf, ReplayError> {
+        fs::create_dir_all(directory)?;
+
+        // Games recorded within the same second with the same seed get a counter suffix
+        let stem = format!("replay-{}-{}", self.header.recorded_at, self.header.seed);
+        for attempt in 0u32.. {
+            let name = match attempt {
+                0 => format!("{}.{}", stem, REPLAY_EXTENSION),
+                _ => format!("{}-{}.{}", stem, attempt, REPLAY_EXTENSION),
+            };
+            let path = directory.join(name);
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(file) => {
+                    self.write_to(BufWriter::new(file))?;
+                    return Ok(path);
+                }
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        unreachable!("ran out of replay file names")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        Self::read_from(BufReader::new(fs::File::open(path)?))
+    }
+}
+
+/// Loads the headers of every replay in `directory`, newest first. Files that
+/// are not readable replays are skipped.
+pub fn list_replays(directory: &Path) -> Result<Vec<(PathBuf, ReplayHeader)>, ReplayError> {
+    let mut replays = Vec::new();
+
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some(REPLAY_EXTENSION) {
+            continue;
+        }
+        if let Ok(header) = ReplayHeader::load(&path) {
+            replays.push((path, header));
+        }
+    }
+
+    replays.sort_by(|(a_path, a), (b_path, b)| {
+        b.recorded_at
+            .cmp(&a.recorded_at)
+            .then_with(|| b_path.cmp(a_path))
+    });
+    Ok(replays)
+}
+
+fn parse_header(line: &str) -> Result<ReplayHeader, ReplayError> {
+    // Check the version before the rest of the header, whose layout may differ
+    let value: serde_json::Value = serde_json::from_str(line)?;
+    let version = value["version"].as_u64().unwrap_or_default() as u32;
+    if version != REPLAY_VERSION {
+        return Err(ReplayError::UnsupportedVersion(version));
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Steps through a replay one recorded tick at a time.
+#[derive(Debug)]
+pub struct ReplayPlayer {
+    replay: Replay,
+    simulation: Simulation,
+    position: usize,
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> Self {
+        let simulation = replay.new_simulation();
+        Self {
+            replay,
+            simulation,
+            position: 0,
+        }
+    }
+
+    /// Plays the next recorded tick. Returns `None` once every tick has been played.
+    pub fn advance(&mut self) -> Option<StepOutcome> {
+        let input = *self.replay.inputs.get(self.position)?;
+        self.position += 1;
+        Some(self.simulation.step(input))
+    }
+
+    /// Plays every remaining tick, ending on the last frame of the game.
+    pub fn jump_to_end(&mut self) {
+        while self.advance().is_some() {}
+    }
+
+    /// Number of ticks played so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.replay.inputs.len()
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    pub fn simulation(&self) -> &Simulation {
+        &self.simulation
+    }
+}
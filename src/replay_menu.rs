@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use snake::replay::ReplayHeader;
+
+pub struct ReplayMenu {
+    items: Vec<(String, PathBuf)>, // Replay summary and file
+    state: ListState,
+}
+
+impl ReplayMenu {
+    pub fn new(replays: Vec<(PathBuf, ReplayHeader)>) -> Self {
+        let items = replays
+            .into_iter()
+            .map(|(path, header)| {
+                let summary = format!(
+                    "Score {:>4} | {} {} | {} | {} ticks | seed {}",
+                    header.score,
+                    header.board,
+                    header.board.topology.name(),
+                    header.difficulty.name(),
+                    header.ticks,
+                    header.seed
+                );
+                (summary, path)
+            })
+            .collect();
+
+        Self {
+            items,
+            state: ListState::default(),
+        }
+    }
+
+    pub fn next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let title = Spans::from(vec![Span::styled(
+            "Replays (newest first)",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )]);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|(summary, _)| ListItem::new(Spans::from(summary.as_str())))
+            .collect();
+
+        let menu = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().bg(Color::Yellow).fg(Color::Black))
+            .highlight_symbol("> ");
+
+        f.render_stateful_widget(menu, chunks[1], &mut self.state);
+    }
+
+    pub fn get_selected_replay(&self) -> Option<PathBuf> {
+        self.state.selected().map(|i| self.items[i].1.clone())
+    }
+}
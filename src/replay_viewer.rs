@@ -0,0 +1,130 @@
+use crate::game::draw_screen;
+use crate::replay::{Replay, ReplayPlayer};
+use crate::screen::{BoardStyle, BoardWidget, GameScreen};
+use crossterm::event::{poll, read, Event, KeyCode};
+use std::time::{Duration, Instant};
+use tui::{backend::Backend, style::Color, text::Spans, Terminal};
+
+/// Playback speeds the viewer cycles through, as multiples of the recorded speed.
+pub const PLAYBACK_SPEEDS: [f32; 4] = [0.5, 1.0, 2.0, 4.0];
+
+/// Terminal front end playing a [`Replay`] back through the normal game renderer.
+///
+/// Controls: `Space`/`P` pause and resume, `N`/`→` step one tick forward
+/// while paused, `+`/`-` change speed, `E`/`End` jump to the end of the game
+/// and `Q`/`Esc` leave the viewer.
+pub struct ReplayViewer {
+    player: ReplayPlayer,
+    speed_index: usize,
+    is_paused: bool,
+    board_style: BoardStyle,
+}
+
+impl ReplayViewer {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            player: ReplayPlayer::new(replay),
+            speed_index: 1,
+            is_paused: false,
+            board_style: BoardStyle::default(),
+        }
+    }
+
+    /// Draws the board in `board_style` instead of the default one.
+    pub fn set_board_style(&mut self, board_style: BoardStyle) {
+        self.board_style = board_style;
+    }
+
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) {
+        let mut last_tick = Instant::now();
+        draw_screen(terminal, self.screen());
+
+        loop {
+            let timeout = if self.is_paused || self.player.is_finished() {
+                Duration::from_millis(250)
+            } else {
+                self.tick_duration().saturating_sub(last_tick.elapsed())
+            };
+
+            if poll(timeout).unwrap() {
+                if let Event::Key(key_event) = read().unwrap() {
+                    match key_event.code {
+                        KeyCode::Char('q') | KeyCode::Esc => break,
+                        KeyCode::Char(' ') | KeyCode::Char('p') => self.is_paused = !self.is_paused,
+                        KeyCode::Char('n') | KeyCode::Right => {
+                            self.is_paused = true;
+                            self.player.advance();
+                        }
+                        KeyCode::Char('+') | KeyCode::Char('=') => {
+                            self.speed_index =
+                                (self.speed_index + 1).min(PLAYBACK_SPEEDS.len() - 1);
+                        }
+                        KeyCode::Char('-') => self.speed_index = self.speed_index.saturating_sub(1),
+                        KeyCode::Char('e') | KeyCode::End => self.player.jump_to_end(),
+                        _ => {}
+                    }
+                    draw_screen(terminal, self.screen());
+                }
+                continue;
+            }
+
+            if !self.is_paused && !self.player.is_finished() {
+                self.player.advance();
+                last_tick = Instant::now();
+                draw_screen(terminal, self.screen());
+            }
+        }
+    }
+
+    fn tick_duration(&self) -> Duration {
+        self.player
+            .replay()
+            .header
+            .difficulty
+            .tick_duration()
+            .div_f32(PLAYBACK_SPEEDS[self.speed_index])
+    }
+
+    /// The board of the replayed game, with the playback state and keys in
+    /// the side panel.
+    fn screen(&self) -> GameScreen {
+        let simulation = self.player.simulation();
+        let state = if let Some(cause) = simulation.death_cause() {
+            format!("Died: {}", cause)
+        } else if simulation.is_won() {
+            "Perfect game".to_string()
+        } else if self.player.is_finished() {
+            "End of replay".to_string()
+        } else if self.is_paused {
+            "Paused".to_string()
+        } else {
+            "Playing".to_string()
+        };
+
+        let board = BoardWidget::new(simulation.board(), self.board_style)
+            .snake(
+                simulation.snake().body(),
+                simulation.snake().direction,
+                Color::LightYellow,
+            )
+            .food(simulation.food().position);
+        let hud = vec![
+            Spans::from(format!(
+                "Tick: {}/{}",
+                self.player.position(),
+                self.player.replay().inputs.len()
+            )),
+            Spans::from(format!("Score: {}", simulation.score())),
+            Spans::from(format!("Speed: {}x", PLAYBACK_SPEEDS[self.speed_index])),
+            Spans::from(state),
+            Spans::from(""),
+            Spans::from("Space: pause"),
+            Spans::from("N: step"),
+            Spans::from("+/-: speed"),
+            Spans::from("E: end"),
+            Spans::from("Q: back"),
+        ];
+
+        GameScreen::new(board, "Replay", hud)
+    }
+}
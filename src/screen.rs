@@ -0,0 +1,589 @@
+use std::{env, fmt, str::FromStr};
+use tui::{
+    buffer::Buffer,
+    layout::{self, Alignment, Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::board::{BoardConfig, BoardError, Topology};
+use crate::constants::HUD_WIDTH;
+use crate::coordinate::Coordinate;
+use crate::direction::Direction;
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+/// How board cells are laid out on the terminal, whose characters are
+/// about twice as tall as they are wide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// One character per cell, so the board looks stretched upwards.
+    #[default]
+    Classic,
+    /// Two cells on top of each other per character, drawn with the
+    /// half-block characters `▀` and `▄`. Cells are square, but snakes and
+    /// food are only told apart by their color.
+    HalfBlock,
+    /// Two characters side by side per cell, so cells are square but the
+    /// board takes twice the width.
+    DoubleWidth,
+}
+
+impl RenderMode {
+    pub const ALL: [RenderMode; 3] = [
+        RenderMode::Classic,
+        RenderMode::HalfBlock,
+        RenderMode::DoubleWidth,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RenderMode::Classic => "Classic",
+            RenderMode::HalfBlock => "Half-block",
+            RenderMode::DoubleWidth => "Double-width",
+        }
+    }
+
+    /// Terminal columns taken by one column of cells.
+    pub fn columns_per_cell(&self) -> u16 {
+        match self {
+            RenderMode::DoubleWidth => 2,
+            RenderMode::Classic | RenderMode::HalfBlock => 1,
+        }
+    }
+
+    /// Rows of cells drawn in one terminal row.
+    pub fn cells_per_row(&self) -> u16 {
+        match self {
+            RenderMode::HalfBlock => 2,
+            RenderMode::Classic | RenderMode::DoubleWidth => 1,
+        }
+    }
+
+    /// Terminal columns and rows taken by `board`.
+    pub fn board_size(&self, board: &BoardConfig) -> (u16, u16) {
+        let cells_per_row = self.cells_per_row() as i32;
+        (
+            (board.width * self.columns_per_cell() as i32) as u16,
+            ((board.height + cells_per_row - 1) / cells_per_row) as u16,
+        )
+    }
+
+    /// The largest board that fits into a terminal of the given size when
+    /// drawn in this mode, leaving room for the side panel with the score.
+    pub fn fill_terminal(&self, columns: u16, rows: u16) -> Result<BoardConfig, BoardError> {
+        BoardConfig::new(
+            (columns as i32 - HUD_WIDTH as i32) / self.columns_per_cell() as i32,
+            rows as i32 * self.cells_per_row() as i32,
+        )
+    }
+
+    /// Checks that `board` drawn in this mode and the side panel next to it
+    /// fit into a terminal of the given size.
+    pub fn validate_for_terminal(
+        &self,
+        board: &BoardConfig,
+        columns: u16,
+        rows: u16,
+    ) -> Result<(), BoardError> {
+        let (width, height) = self.board_size(board);
+        if width as i32 + HUD_WIDTH as i32 > columns as i32 || height > rows {
+            return Err(BoardError::TooLarge {
+                width: board.width,
+                height: board.height,
+                columns,
+                rows,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for RenderMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "classic" => Ok(RenderMode::Classic),
+            "half-block" | "halfblock" => Ok(RenderMode::HalfBlock),
+            "double-width" | "doublewidth" => Ok(RenderMode::DoubleWidth),
+            _ => Err(format!("unknown display '{}'", value)),
+        }
+    }
+}
+
+impl fmt::Display for RenderMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Characters the snakes are drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Glyphs {
+    /// Arrow heads and box-drawing lines, e.g. `╶──┐▼`.
+    #[default]
+    Unicode,
+    /// Plain ASCII for terminals and fonts without box-drawing characters,
+    /// e.g. `.--+v`.
+    Ascii,
+}
+
+impl Glyphs {
+    /// Unicode when the locale uses UTF-8 and the terminal is not the Linux
+    /// console, ASCII otherwise.
+    pub fn detect() -> Self {
+        if cfg!(windows) {
+            return Glyphs::Unicode;
+        }
+
+        let term = env::var("TERM").unwrap_or_default();
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if matches!(term.as_str(), "linux" | "dumb")
+            || !(locale.contains("utf-8") || locale.contains("utf8"))
+        {
+            return Glyphs::Ascii;
+        }
+
+        Glyphs::Unicode
+    }
+}
+
+impl FromStr for Glyphs {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "unicode" => Ok(Glyphs::Unicode),
+            "ascii" => Ok(Glyphs::Ascii),
+            _ => Err(format!("unknown glyphs '{}'", value)),
+        }
+    }
+}
+
+/// How the board is drawn: the layout of its cells and the characters of
+/// the snakes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BoardStyle {
+    pub render_mode: RenderMode,
+    pub glyphs: Glyphs,
+}
+
+/// A part of a snake, with the directions of the segments it is joined to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Segment {
+    /// The head, facing where the snake is going, and the neck behind it.
+    Head {
+        facing: Direction,
+        neck: Option<Direction>,
+    },
+    /// A segment joined to the one closer to the head and the one closer
+    /// to the tail. Links are missing where the neighbours do not touch.
+    Body(Option<Direction>, Option<Direction>),
+    /// The tip of the tail, joined to the segment before it.
+    Tail(Option<Direction>),
+}
+
+impl Segment {
+    fn symbol(&self, glyphs: Glyphs) -> char {
+        use Direction::*;
+
+        let ascii = glyphs == Glyphs::Ascii;
+        match *self {
+            Segment::Head { facing, .. } => match (facing, ascii) {
+                (Up, false) => '▲',
+                (Down, false) => '▼',
+                (Left, false) => '◀',
+                (Right, false) => '▶',
+                (Up, true) => '^',
+                (Down, true) => 'v',
+                (Left, true) => '<',
+                (Right, true) => '>',
+            },
+            Segment::Body(Some(front), Some(back)) => {
+                let straight = |direction| match (direction, ascii) {
+                    (Up | Down, false) => '│',
+                    (Left | Right, false) => '─',
+                    (Up | Down, true) => '|',
+                    (Left | Right, true) => '-',
+                };
+                match (front, back) {
+                    _ if front == back || front == back.opposite() => straight(front),
+                    _ if ascii => '+',
+                    (Down, Right) | (Right, Down) => '┌',
+                    (Down, Left) | (Left, Down) => '┐',
+                    (Up, Right) | (Right, Up) => '└',
+                    _ => '┘',
+                }
+            }
+            // One of the neighbouring segments doesn't touch this one, so only
+            // the other link is known
+            Segment::Body(Some(link), None) | Segment::Body(None, Some(link)) => {
+                Segment::Body(Some(link), Some(link)).symbol(glyphs)
+            }
+            Segment::Tail(Some(link)) => match (link, ascii) {
+                (_, true) => '.',
+                (Up, false) => '╵',
+                (Down, false) => '╷',
+                (Left, false) => '╴',
+                (Right, false) => '╶',
+            },
+            Segment::Body(None, None) | Segment::Tail(None) => '@',
+        }
+    }
+
+    /// Whether the segment is joined to the one on its right, so that a
+    /// double-width cell continues the line into its second column.
+    fn joins_right(&self) -> bool {
+        let links = match *self {
+            Segment::Head { neck, .. } => [neck, None],
+            Segment::Body(front, back) => [front, back],
+            Segment::Tail(link) => [link, None],
+        };
+        links.contains(&Some(Direction::Right))
+    }
+}
+
+/// What a cell of the board shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    Border,
+    Snake(Color, Segment),
+    Food,
+}
+
+/// The board with its snakes and food, drawn centered in its area in the
+/// given [`BoardStyle`].
+///
+/// Built up like `BoardWidget::new(board, style).snake(body, direction, color).food(position)`.
+/// The border is drawn over everything else, and snakes over the food and
+/// over the snakes added after them.
+#[derive(Debug, Clone)]
+pub struct BoardWidget {
+    board: BoardConfig,
+    style: BoardStyle,
+    tiles: Vec<Tile>,
+}
+
+impl BoardWidget {
+    pub fn new(board: &BoardConfig, style: BoardStyle) -> Self {
+        let mut tiles = vec![Tile::Empty; (board.width * board.height).max(0) as usize];
+        for y in 0..board.height {
+            for x in 0..board.width {
+                if board.is_border(Coordinate(x, y)) {
+                    tiles[(y * board.width + x) as usize] = Tile::Border;
+                }
+            }
+        }
+
+        Self {
+            board: *board,
+            style,
+            tiles,
+        }
+    }
+
+    /// Adds a snake with the given body, from head to tail, heading in
+    /// `direction`.
+    pub fn snake(mut self, body: &[Coordinate], direction: Direction, color: Color) -> Self {
+        for (index, &position) in body.iter().enumerate() {
+            let front = index
+                .checked_sub(1)
+                .and_then(|previous| self.link(position, body[previous]));
+            let back = body
+                .get(index + 1)
+                .and_then(|&next| self.link(position, next));
+            let segment = if index == 0 {
+                Segment::Head {
+                    facing: direction,
+                    neck: back,
+                }
+            } else if index == body.len() - 1 {
+                Segment::Tail(front)
+            } else {
+                Segment::Body(front, back)
+            };
+
+            if let Some(tile) = self.tile_mut(position) {
+                if matches!(tile, Tile::Empty | Tile::Food) {
+                    *tile = Tile::Snake(color, segment);
+                }
+            }
+        }
+        self
+    }
+
+    /// The direction from `from` to the neighbouring cell `to`, across the
+    /// edges on wrapping boards.
+    fn link(&self, from: Coordinate, to: Coordinate) -> Option<Direction> {
+        DIRECTIONS
+            .into_iter()
+            .find(|&direction| self.board.next_position(from, direction) == to)
+    }
+
+    pub fn food(mut self, position: Coordinate) -> Self {
+        if let Some(tile) = self.tile_mut(position) {
+            if matches!(tile, Tile::Empty) {
+                *tile = Tile::Food;
+            }
+        }
+        self
+    }
+
+    fn tile(&self, x: i32, y: i32) -> Option<Tile> {
+        if x < 0 || y < 0 || x >= self.board.width || y >= self.board.height {
+            return None;
+        }
+        self.tiles.get((y * self.board.width + x) as usize).copied()
+    }
+
+    fn tile_mut(&mut self, Coordinate(x, y): Coordinate) -> Option<&mut Tile> {
+        if x < 0 || y < 0 || x >= self.board.width || y >= self.board.height {
+            return None;
+        }
+        self.tiles.get_mut((y * self.board.width + x) as usize)
+    }
+
+    /// The symbol and background color of a tile.
+    fn look(&self, tile: Tile) -> (char, Color) {
+        match tile {
+            Tile::Empty => (' ', Color::Black),
+            // Wrapping boards have no walls, only a frame around the playable area
+            Tile::Border => match self.board.topology {
+                Topology::Walled => (' ', Color::LightBlue),
+                Topology::Wrapping => (' ', Color::DarkGray),
+            },
+            Tile::Snake(color, segment) => (segment.symbol(self.style.glyphs), color),
+            Tile::Food => ('*', Color::LightRed),
+        }
+    }
+
+    /// The symbol and style of the character at `column` and `row` of the
+    /// board on screen.
+    fn cell(&self, column: i32, row: i32) -> (char, Style) {
+        match self.style.render_mode {
+            RenderMode::Classic => {
+                let tile = self.tile(column, row).unwrap();
+                let (symbol, background) = self.look(tile);
+                (symbol, Self::style(tile, background))
+            }
+            RenderMode::DoubleWidth => {
+                let tile = self.tile(column / 2, row).unwrap();
+                let (symbol, background) = self.look(tile);
+                // The symbol goes in the left half of the cell, and snakes
+                // running to the right continue into the other half
+                let symbol = match tile {
+                    _ if column % 2 == 0 => symbol,
+                    Tile::Snake(_, segment) if segment.joins_right() => {
+                        Segment::Body(Some(Direction::Left), Some(Direction::Right))
+                            .symbol(self.style.glyphs)
+                    }
+                    _ => ' ',
+                };
+                (symbol, Self::style(tile, background))
+            }
+            RenderMode::HalfBlock => {
+                // Too small for glyphs, snakes only show as their color
+                let top = self.tile(column, row * 2).map(|tile| self.look(tile).1);
+                let bottom = self.tile(column, row * 2 + 1).map(|tile| self.look(tile).1);
+                match (top, bottom) {
+                    (Some(top), Some(bottom)) if top == bottom => {
+                        (' ', Style::default().fg(Color::Reset).bg(top))
+                    }
+                    (Some(top), Some(bottom)) => ('▀', Style::default().fg(top).bg(bottom)),
+                    (Some(top), None) => ('▀', Style::default().fg(top).bg(Color::Reset)),
+                    (None, Some(bottom)) => ('▄', Style::default().fg(bottom).bg(Color::Reset)),
+                    (None, None) => (' ', Style::default()),
+                }
+            }
+        }
+    }
+
+    /// Snakes are drawn in black on their color, everything else only has
+    /// a background.
+    fn style(tile: Tile, background: Color) -> Style {
+        let foreground = match tile {
+            Tile::Snake(..) => Color::Black,
+            _ => Color::Reset,
+        };
+        Style::default().fg(foreground).bg(background)
+    }
+}
+
+impl Widget for BoardWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (width, height) = self.style.render_mode.board_size(&self.board);
+        // Boards larger than the area are cut off at the right and bottom
+        let x_offset = area.width.saturating_sub(width) / 2;
+        let y_offset = area.height.saturating_sub(height) / 2;
+
+        for row in 0..height.min(area.height) {
+            for column in 0..width.min(area.width) {
+                let (symbol, style) = self.cell(column as i32, row as i32);
+                buf.get_mut(area.x + x_offset + column, area.y + y_offset + row)
+                    .set_char(symbol)
+                    .set_style(style);
+            }
+        }
+    }
+}
+
+/// A box drawn over the middle of the board, such as the pause menu or the
+/// result of a game.
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    title: String,
+    lines: Vec<String>,
+    /// The highlighted line of a menu.
+    selected: Option<usize>,
+}
+
+impl Overlay {
+    /// A menu of `items` with the `selected` one highlighted.
+    pub fn menu(title: &str, items: Vec<String>, selected: usize) -> Self {
+        Self {
+            title: title.to_string(),
+            lines: items,
+            selected: Some(selected),
+        }
+    }
+
+    pub fn message(title: &str, lines: Vec<String>) -> Self {
+        Self {
+            title: title.to_string(),
+            lines,
+            selected: None,
+        }
+    }
+}
+
+impl Widget for Overlay {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let marker_width = if self.selected.is_some() { 2 } else { 0 };
+        let width = self
+            .lines
+            .iter()
+            .map(|line| line.chars().count() + marker_width)
+            .chain([self.title.chars().count()])
+            .max()
+            .unwrap_or_default() as u16
+            + 6;
+        let height = self.lines.len() as u16 + 4;
+        let overlay = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width: width.min(area.width),
+            height: height.min(area.height),
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(
+                self.title,
+                Style::default().add_modifier(Modifier::BOLD),
+            ))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().fg(Color::White).bg(Color::Blue));
+        let inner = block.inner(overlay);
+        Clear.render(overlay, buf);
+        block.render(overlay, buf);
+
+        for (index, line) in self.lines.iter().enumerate() {
+            let y = inner.y + 1 + index as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+
+            let (text, style) = match self.selected {
+                Some(selected) if selected == index => (
+                    format!(" > {}", line),
+                    Style::default().fg(Color::Black).bg(Color::Yellow),
+                ),
+                Some(_) => (format!("   {}", line), Style::default()),
+                None => (format!(" {}", line), Style::default()),
+            };
+            let row = Rect {
+                y,
+                height: 1,
+                ..inner
+            };
+            buf.set_style(row, style);
+            buf.set_stringn(row.x, row.y, text, row.width as usize, style);
+        }
+    }
+}
+
+/// Everything shown while a game runs: the board, a side panel with the
+/// scores and help, and optionally an overlay over the board.
+#[derive(Debug, Clone)]
+pub struct GameScreen {
+    board: BoardWidget,
+    title: String,
+    hud: Vec<Spans<'static>>,
+    overlay: Option<Overlay>,
+}
+
+impl GameScreen {
+    /// A screen showing `board`, with the side panel titled `title`
+    /// listing `hud`.
+    pub fn new(board: BoardWidget, title: &str, hud: Vec<Spans<'static>>) -> Self {
+        Self {
+            board,
+            title: title.to_string(),
+            hud,
+            overlay: None,
+        }
+    }
+
+    pub fn overlay(mut self, overlay: Overlay) -> Self {
+        self.overlay = Some(overlay);
+        self
+    }
+}
+
+impl Widget for GameScreen {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let chunks = Layout::default()
+            .direction(layout::Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(HUD_WIDTH)].as_ref())
+            .split(area);
+
+        self.board.render(chunks[0], buf);
+
+        let title = Span::styled(
+            self.title,
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        );
+        Paragraph::new(self.hud)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false })
+            .render(chunks[1], buf);
+
+        if let Some(overlay) = self.overlay {
+            overlay.render(chunks[0], buf);
+        }
+    }
+}
+
+/// A line of the side panel naming a player in the color of their snake.
+pub fn player_line(name: &str, color: Color) -> Spans<'static> {
+    Spans::from(Span::styled(
+        name.to_string(),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
+}
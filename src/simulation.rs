@@ -0,0 +1,199 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{
+    board::BoardConfig, direction::Direction, food::Food, snake::Snake, strategy::GameView,
+};
+
+/// The reason a game came to an end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeathCause {
+    /// The snake's head ran into the border of the board.
+    Wall,
+    /// The snake's head ran into one of its own segments.
+    SelfCollision,
+    /// The snake's head ran into the body of another snake.
+    OtherSnake,
+    /// The snake met another snake head to head.
+    HeadOn,
+    /// The player left a network game.
+    Disconnected,
+}
+
+impl fmt::Display for DeathCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            DeathCause::Wall => "Hit the wall",
+            DeathCause::SelfCollision => "Ran into itself",
+            DeathCause::OtherSnake => "Ran into another snake",
+            DeathCause::HeadOn => "Crashed head-on",
+            DeathCause::Disconnected => "Left the game",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The result of advancing the simulation by a single tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The snake moved without eating anything.
+    Moved,
+    /// The snake ate the food and will grow on the next tick.
+    Ate,
+    /// The snake died. Further calls to `step` keep returning this outcome.
+    Died(DeathCause),
+    /// The snake ate the last food and fills the whole board, a perfect game.
+    /// Further calls to `step` keep returning this outcome.
+    Won,
+}
+
+/// Headless game engine holding the rules of Snake (moving, eating, colliding).
+///
+/// A `Simulation` never touches the terminal or the audio device, so it can be
+/// driven from tests, bots or any other front end by calling [`Simulation::step`]
+/// once per tick. Every random event is drawn from an RNG seeded with
+/// [`Simulation::seed`], so the same seed and input sequence always produce
+/// the same game. The inputs of every tick are kept so that the game can be
+/// saved as a replay.
+#[derive(Debug)]
+pub struct Simulation {
+    board: BoardConfig,
+    seed: u64,
+    rng: ChaCha8Rng,
+    snake: Snake,
+    food: Food,
+    score: u32,
+    ticks: u64,
+    inputs: Vec<Option<Direction>>,
+    death_cause: Option<DeathCause>,
+    won: bool,
+}
+
+impl Simulation {
+    pub fn new(board: BoardConfig, seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let snake = Snake::new(board);
+        let food = Food::new(&board, &snake, &mut rng).expect("a new board has free cells");
+        Self {
+            board,
+            seed,
+            rng,
+            snake,
+            food,
+            score: 0,
+            ticks: 0,
+            inputs: Vec::new(),
+            death_cause: None,
+            won: false,
+        }
+    }
+
+    /// Advances the game by one tick, optionally turning the snake first.
+    ///
+    /// Turning into the opposite direction is ignored, exactly like
+    /// `Snake::change_direction`.
+    pub fn step(&mut self, input: Option<Direction>) -> StepOutcome {
+        if let Some(cause) = self.death_cause {
+            return StepOutcome::Died(cause);
+        }
+        if self.won {
+            return StepOutcome::Won;
+        }
+
+        if let Some(direction) = input {
+            self.snake.change_direction(direction);
+        }
+
+        self.snake.move_forward();
+        self.ticks += 1;
+        self.inputs.push(input);
+
+        let death_cause = if self.snake.collides_with_wall() {
+            Some(DeathCause::Wall)
+        } else if self.snake.collides_with_self() {
+            Some(DeathCause::SelfCollision)
+        } else {
+            None
+        };
+
+        if let Some(cause) = death_cause {
+            self.death_cause = Some(cause);
+            return StepOutcome::Died(cause);
+        }
+
+        if self.snake.head_position() == self.food.position {
+            self.snake.grow();
+            self.score += 1;
+
+            return match Food::new(&self.board, &self.snake, &mut self.rng) {
+                Some(food) => {
+                    self.food = food;
+                    StepOutcome::Ate
+                }
+                // No free cell is left for the next food
+                None => {
+                    self.won = true;
+                    StepOutcome::Won
+                }
+            };
+        }
+
+        StepOutcome::Moved
+    }
+
+    pub fn board(&self) -> &BoardConfig {
+        &self.board
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn snake(&self) -> &Snake {
+        &self.snake
+    }
+
+    pub fn food(&self) -> &Food {
+        &self.food
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Number of ticks the simulation has been advanced so far.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Read-only view of the game for a [`Strategy`](crate::strategy::Strategy).
+    pub fn view(&self) -> GameView<'_> {
+        GameView {
+            board: &self.board,
+            snake: &self.snake,
+            food: self.food.position,
+            score: self.score,
+            opponents: Vec::new(),
+        }
+    }
+
+    /// The input passed to `step` on every tick so far, in order.
+    pub fn inputs(&self) -> &[Option<Direction>] {
+        &self.inputs
+    }
+
+    pub fn death_cause(&self) -> Option<DeathCause> {
+        self.death_cause
+    }
+
+    /// Whether the snake filled the whole board.
+    pub fn is_won(&self) -> bool {
+        self.won
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.death_cause.is_some() || self.won
+    }
+}
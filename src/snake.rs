@@ -1,8 +1,4 @@
-use crate::{
-    constants::{BOARD_HEIGHT, BOARD_WIDTH},
-    coordinate::Coordinate,
-    direction::Direction,
-};
+use crate::{board::BoardConfig, coordinate::Coordinate, direction::Direction};
 
 /// Represents the Snake in the classic Snake game, encapsulating its movement,
 /// growth, and collision logic. The Snake is composed of a body, represented as a vector
@@ -14,20 +10,25 @@ use crate::{
 ///   The first element is the head of the Snake, and the remaining elements represent its body.
 /// - `direction`: The current direction of movement of the Snake, represented as a `Direction` enum.
 /// - `has_eaten`: A boolean flag that determines whether the Snake has eaten food and should grow in size.
+/// - `board`: The board the Snake lives on, used to detect collisions with its border.
 ///
 /// # Methods
 ///
-/// - `new() -> Snake`:
-///   Creates a new Snake object with an initial size of one segment, located at the center of the game board.
+/// - `new(board: BoardConfig) -> Snake`:
+///   Creates a new Snake object with an initial size of one segment, located at the center of the given board.
 ///   The initial direction is set to `Direction::Right`, and `has_eaten` is set to `false`.
 ///
+/// - `spawn(board: BoardConfig, position: Coordinate, direction: Direction) -> Snake`:
+///   Creates a one-segment Snake at the given position moving in the given direction, e.g. to
+///   start several snakes on the same board.
+///
 /// - `change_direction(&mut self, direction: Direction)`:
 ///   Updates the direction of the Snake, ensuring that the new direction is not directly opposite to the current one.
 ///   This prevents the Snake from moving into itself, which would result in a collision.
 ///
 /// - `move_forward(&mut self)`:
 ///   Moves the Snake forward in its current direction by adding a new head at the next position
-///   based on the direction. If the Snake has not eaten, its tail is removed to simulate forward motion.
+///   based on the direction, wrapping around the edges on a wrapping board. If the Snake has not eaten, its tail is removed to simulate forward motion.
 ///   If the Snake has eaten, the tail remains, effectively growing the Snake.
 ///
 /// - `head_position(&self) -> Coordinate`:
@@ -43,7 +44,7 @@ use crate::{
 ///
 /// - `collides_with_wall(&self) -> bool`:
 ///   Determines whether the Snake's head has collided with the boundary of the game board.
-///   Returns `true` if the head moves out of bounds, otherwise `false`.
+///   Returns `true` if the head moves out of bounds, otherwise `false`. Always `false` on a wrapping board.
 ///
 /// - `body(&self) -> &Vec<Coordinate>`:
 ///   Returns a reference to the vector representing the Snake's body segments. This allows
@@ -52,7 +53,8 @@ use crate::{
 /// # Example Usage
 ///
 /// ```rust
-/// let mut snake = Snake::new();
+/// # use snake::{board::BoardConfig, direction::Direction, snake::Snake};
+/// let mut snake = Snake::new(BoardConfig::default());
 /// snake.change_direction(Direction::Up);
 /// snake.move_forward();
 /// if snake.collides_with_self() || snake.collides_with_wall() {
@@ -64,14 +66,25 @@ pub struct Snake {
     pub body: Vec<Coordinate>,
     pub direction: Direction,
     pub has_eaten: bool,
+    board: BoardConfig,
 }
 
 impl Snake {
-    pub fn new() -> Self {
+    pub fn new(board: BoardConfig) -> Self {
         Self {
-            body: vec![Coordinate(BOARD_WIDTH / 2, BOARD_HEIGHT / 2)],
+            body: vec![board.center()],
             direction: Direction::Right,
             has_eaten: false,
+            board,
+        }
+    }
+
+    pub fn spawn(board: BoardConfig, position: Coordinate, direction: Direction) -> Self {
+        Self {
+            body: vec![position],
+            direction,
+            has_eaten: false,
+            board,
         }
     }
 
@@ -98,14 +111,9 @@ impl Snake {
     }
 
     pub fn move_forward(&mut self) {
-        let coordinate = self.head_position();
-
-        let new_head = match self.direction {
-            Direction::Up => Coordinate(coordinate.0, coordinate.1 - 1),
-            Direction::Down => Coordinate(coordinate.0, coordinate.1 + 1),
-            Direction::Left => Coordinate(coordinate.0 - 1, coordinate.1),
-            Direction::Right => Coordinate(coordinate.0 + 1, coordinate.1),
-        };
+        let new_head = self
+            .board
+            .next_position(self.head_position(), self.direction);
 
         self.body.insert(0, new_head);
 
@@ -130,11 +138,7 @@ impl Snake {
     }
 
     pub fn collides_with_wall(&self) -> bool {
-        let coordinate = self.head_position();
-        coordinate.0 <= 0
-            || coordinate.0 >= BOARD_WIDTH - 1
-            || coordinate.1 <= 0
-            || coordinate.1 >= BOARD_HEIGHT - 1
+        self.board.is_wall(self.head_position())
     }
 
     pub fn body(&self) -> &Vec<Coordinate> {
@@ -144,6 +148,6 @@ impl Snake {
 
 impl Default for Snake {
     fn default() -> Self {
-        Self::new()
+        Self::new(BoardConfig::default())
     }
 }
@@ -0,0 +1,42 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::direction::Direction;
+
+use super::{GameView, Strategy};
+
+/// Plays like another strategy, but now and then misses a turn and keeps
+/// going straight, e.g. to make a beatable computer opponent. The lapses are
+/// drawn from a seeded RNG, so the same seed always misses the same turns.
+pub struct ClumsyStrategy {
+    strategy: Box<dyn Strategy>,
+    /// Chance between 0 and 1 of ignoring the strategy on any given tick.
+    lapse_chance: f64,
+    rng: ChaCha8Rng,
+}
+
+impl ClumsyStrategy {
+    pub fn new(strategy: Box<dyn Strategy>, lapse_chance: f64, seed: u64) -> Self {
+        Self {
+            strategy,
+            lapse_chance: lapse_chance.clamp(0.0, 1.0),
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Strategy for ClumsyStrategy {
+    fn name(&self) -> &str {
+        self.strategy.name()
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        // Keep asking on lapses, so stateful strategies see every tick
+        let direction = self.strategy.next_direction(view);
+        if self.rng.gen_bool(self.lapse_chance) {
+            view.snake.direction
+        } else {
+            direction
+        }
+    }
+}
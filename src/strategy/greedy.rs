@@ -0,0 +1,55 @@
+use crate::direction::Direction;
+
+use super::{GameView, Strategy};
+
+/// Moves to whichever safe neighbouring cell is closest to the food, only
+/// looking one step ahead. Cells taken by opponents count as unsafe.
+#[derive(Debug, Default)]
+pub struct GreedyStrategy;
+
+impl Strategy for GreedyStrategy {
+    fn name(&self) -> &str {
+        "Greedy"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        let snake = view.snake;
+        let board = view.board;
+        let head = snake.head_position();
+
+        // Helper function to check if the next position in a given direction is safe
+        let is_safe = |direction: Direction| -> bool {
+            let next_position = board.next_position(head, direction);
+            !snake.body.contains(&next_position)
+                && !board.is_wall(next_position)
+                && !view
+                    .opponents
+                    .iter()
+                    .any(|opponent| opponent.body.contains(&next_position))
+        };
+
+        // Check all directions and choose the best one
+        let directions = [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ];
+
+        let mut best_direction = snake.direction;
+        let mut min_distance = i32::MAX;
+
+        for &dir in &directions {
+            if dir != snake.direction.opposite() && is_safe(dir) {
+                let next_pos = board.next_position(head, dir);
+                let distance = board.distance(next_pos, view.food);
+                if distance < min_distance {
+                    min_distance = distance;
+                    best_direction = dir;
+                }
+            }
+        }
+
+        best_direction
+    }
+}
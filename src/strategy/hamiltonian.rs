@@ -0,0 +1,167 @@
+use crate::{board::BoardConfig, coordinate::Coordinate, direction::Direction};
+
+use super::{GameView, PathfindingStrategy, Strategy};
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+/// Follows a Hamiltonian cycle through every playable cell, so the snake can
+/// never trap itself and eventually fills the whole board.
+///
+/// While the snake is short it takes shortcuts towards the food, but only
+/// ones that keep the snake's body in cycle order ahead of its tail. Boards
+/// whose playable area has an odd width and an odd height have no such
+/// cycle; on those it plays like [`PathfindingStrategy`].
+#[derive(Debug, Default)]
+pub struct HamiltonianStrategy {
+    cycle: Option<Cycle>,
+    fallback: PathfindingStrategy,
+}
+
+impl Strategy for HamiltonianStrategy {
+    fn name(&self) -> &str {
+        "Hamiltonian"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        if self.cycle.as_ref().map(|cycle| cycle.board) != Some(*view.board) {
+            self.cycle = Cycle::new(view.board);
+        }
+
+        match &self.cycle {
+            Some(cycle) => cycle.next_direction(view),
+            None => self.fallback.next_direction(view),
+        }
+    }
+}
+
+/// A closed path visiting every playable cell of a board exactly once.
+#[derive(Debug)]
+struct Cycle {
+    board: BoardConfig,
+    /// Position of every cell along the cycle, indexed by cell.
+    order: Vec<usize>,
+    length: usize,
+}
+
+impl Cycle {
+    /// Lays the cycle out as a zigzag over every row but the first column,
+    /// returning up that column. Needs an even number of rows, otherwise
+    /// the zigzag runs over columns instead.
+    fn new(board: &BoardConfig) -> Option<Self> {
+        let columns = board.width - 2;
+        let rows = board.height - 2;
+
+        let zigzag = |major: i32, minor: i32| -> Vec<(i32, i32)> {
+            let mut cells = Vec::new();
+            for i in 0..major {
+                let line: Vec<i32> = if i % 2 == 0 {
+                    (1..minor).collect()
+                } else {
+                    (1..minor).rev().collect()
+                };
+                cells.extend(line.into_iter().map(|j| (i, j)));
+            }
+            cells.extend((0..major).rev().map(|i| (i, 0)));
+            cells
+        };
+
+        let cells: Vec<Coordinate> = if rows % 2 == 0 {
+            zigzag(rows, columns)
+                .into_iter()
+                .map(|(row, column)| Coordinate(column + 1, row + 1))
+                .collect()
+        } else if columns % 2 == 0 {
+            zigzag(columns, rows)
+                .into_iter()
+                .map(|(column, row)| Coordinate(column + 1, row + 1))
+                .collect()
+        } else {
+            return None;
+        };
+
+        let mut order = vec![0; (board.width * board.height) as usize];
+        for (position, &cell) in cells.iter().enumerate() {
+            order[(cell.1 * board.width + cell.0) as usize] = position;
+        }
+
+        Some(Self {
+            board: *board,
+            order,
+            length: cells.len(),
+        })
+    }
+
+    fn position(&self, cell: Coordinate) -> usize {
+        self.order[(cell.1 * self.board.width + cell.0) as usize]
+    }
+
+    /// Number of steps along the cycle from `from` to `to`.
+    fn distance(&self, from: Coordinate, to: Coordinate) -> usize {
+        (self.position(to) + self.length - self.position(from)) % self.length
+    }
+
+    fn next_direction(&self, view: &GameView) -> Direction {
+        let board = view.board;
+        let snake = view.snake;
+        let body = snake.body();
+        let head = body[0];
+        let tail = body[body.len() - 1];
+
+        let distance_to_food = self.distance(head, view.food);
+        let distance_to_tail = if body.len() == 1 {
+            self.length
+        } else {
+            self.distance(head, tail)
+        };
+
+        // Skipping part of the cycle is only safe while the head stays behind
+        // the tail, leaving room for the growth still to come. Once the snake
+        // covers half of the board it strictly follows the cycle.
+        let growth = usize::from(snake.has_eaten) + 1;
+        let free_cells = self.length - body.len();
+        let mut shortcut_limit = if body.len() * 2 < self.length {
+            distance_to_tail.saturating_sub(growth + 3)
+        } else {
+            0
+        };
+        if distance_to_food < distance_to_tail {
+            shortcut_limit = shortcut_limit.saturating_sub(1);
+            if (distance_to_tail - distance_to_food) * 4 > free_cells {
+                shortcut_limit = shortcut_limit.saturating_sub(10);
+            }
+        }
+
+        let candidates = DIRECTIONS
+            .into_iter()
+            .filter(|&direction| direction != snake.direction.opposite())
+            .map(|direction| {
+                let next = board.next_position(head, direction);
+                (direction, next, self.distance(head, next))
+            })
+            .filter(|&(_, next, _)| {
+                !board.is_wall(next) && !body[..body.len() - 1].contains(&next)
+            });
+
+        let mut best: Option<(Direction, usize)> = None;
+        let mut fallback = None;
+        for (direction, _, distance) in candidates {
+            if distance == 1 || (distance <= shortcut_limit && distance <= distance_to_food) {
+                if best.is_none_or(|(_, best_distance)| distance > best_distance) {
+                    best = Some((direction, distance));
+                }
+            } else if body.len() == 1 {
+                // The snake cannot turn back onto the cycle, any free cell is safe
+                fallback = fallback.or(Some(direction));
+            }
+        }
+
+        best.map(|(direction, _)| direction)
+            .or(fallback)
+            .unwrap_or(snake.direction)
+    }
+}
@@ -0,0 +1,94 @@
+mod clumsy;
+mod greedy;
+mod hamiltonian;
+mod pathfinding;
+mod process;
+pub mod protocol;
+mod tcp;
+
+pub use clumsy::ClumsyStrategy;
+pub use greedy::GreedyStrategy;
+pub use hamiltonian::HamiltonianStrategy;
+pub use pathfinding::PathfindingStrategy;
+pub use process::ProcessStrategy;
+pub use tcp::{BotServer, TcpStrategy};
+
+use crate::{board::BoardConfig, coordinate::Coordinate, direction::Direction, snake::Snake};
+
+/// Read-only view of a game, handed to a [`Strategy`] every tick.
+#[derive(Debug, Clone)]
+pub struct GameView<'a> {
+    pub board: &'a BoardConfig,
+    pub snake: &'a Snake,
+    pub food: Coordinate,
+    pub score: u32,
+    /// The other snakes still on the board in a versus game, empty when
+    /// playing alone.
+    pub opponents: Vec<&'a Snake>,
+}
+
+/// Something that steers a snake, such as the autopilot.
+///
+/// Implement this trait to plug your own AI into the game and add it to a
+/// [`StrategyRegistry`] to make it selectable by name.
+pub trait Strategy {
+    /// Name shown in the autopilot menu.
+    fn name(&self) -> &str;
+
+    /// Picks the direction to move in on this tick. Returning the opposite of
+    /// the snake's current direction has no effect.
+    fn next_direction(&mut self, view: &GameView) -> Direction;
+}
+
+type StrategyFactory = Box<dyn Fn() -> Box<dyn Strategy>>;
+
+/// Named strategies that can be created on demand, e.g. from a menu.
+pub struct StrategyRegistry {
+    factories: Vec<(String, StrategyFactory)>,
+}
+
+impl StrategyRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self {
+            factories: Vec::new(),
+        }
+    }
+
+    /// A registry holding every strategy that ships with the game.
+    pub fn with_builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register("Greedy", || Box::new(GreedyStrategy));
+        registry.register("Pathfinding", || Box::new(PathfindingStrategy));
+        registry.register("Hamiltonian", || Box::<HamiltonianStrategy>::default());
+        registry
+    }
+
+    /// Adds a strategy under `name`, replacing any strategy registered with
+    /// the same name before.
+    pub fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn() -> Box<dyn Strategy> + 'static,
+    {
+        self.factories.retain(|(existing, _)| existing != name);
+        self.factories.push((name.to_string(), Box::new(factory)));
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Creates a fresh instance of the strategy registered under `name`.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Strategy>> {
+        self.factories
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, factory)| factory())
+    }
+}
+
+impl Default for StrategyRegistry {
+    fn default() -> Self {
+        Self::with_builtin()
+    }
+}
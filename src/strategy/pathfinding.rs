@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+
+use crate::{board::BoardConfig, coordinate::Coordinate, direction::Direction, snake::Snake};
+
+use super::{GameView, Strategy};
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+/// Searches for the shortest path to the food and only takes it when the
+/// snake could still reach its own tail after eating, so it never seals
+/// itself into a dead end. When there is no such path it follows its tail
+/// until the body moves out of the way. Opponents' bodies are avoided like
+/// its own, and so are the cells an opponent's head could move into next.
+#[derive(Debug, Default)]
+pub struct PathfindingStrategy;
+
+impl Strategy for PathfindingStrategy {
+    fn name(&self) -> &str {
+        "Pathfinding"
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        let board = view.board;
+        let snake = view.snake;
+        let body = snake.body();
+        let mut obstacles = Obstacles::new(board, body, snake.has_eaten);
+        obstacles.add_opponents(board, &view.opponents, 0);
+
+        if let Some(path) = shortest_path(board, &obstacles, body[0], snake.direction, view.food) {
+            if can_reach_tail_after(board, body, snake.has_eaten, &view.opponents, &path, true) {
+                return path[0];
+            }
+        }
+
+        // Follow the tail the long way round, giving the body time to clear the way to the food
+        let tail = body[body.len() - 1];
+        let tail_chasing_direction = DIRECTIONS
+            .into_iter()
+            .filter(|&direction| direction != snake.direction.opposite())
+            .filter(|&direction| {
+                let next = board.next_position(body[0], direction);
+                obstacles.is_free(board, next, 1)
+                    && can_reach_tail_after(
+                        board,
+                        body,
+                        snake.has_eaten,
+                        &view.opponents,
+                        &[direction],
+                        next == view.food,
+                    )
+            })
+            .max_by_key(|&direction| board.distance(board.next_position(body[0], direction), tail));
+        if let Some(direction) = tail_chasing_direction {
+            return direction;
+        }
+
+        // Too short to have a tail to follow, or already trapped: head for the most room
+        roomiest_direction(board, &obstacles, body[0], snake.direction)
+    }
+}
+
+/// When each cell of the board stops being blocked by the snake's body.
+struct Obstacles {
+    width: i32,
+    /// Number of ticks until the body segment on a cell has moved on, indexed
+    /// by cell. Zero for cells the snake does not occupy.
+    free_at: Vec<usize>,
+}
+
+impl Obstacles {
+    /// `growing` is whether the snake has just eaten, keeping every segment
+    /// in place for one more tick.
+    fn new(board: &BoardConfig, body: &[Coordinate], growing: bool) -> Self {
+        let mut obstacles = Self {
+            width: board.width,
+            free_at: vec![0; (board.width * board.height) as usize],
+        };
+
+        for (i, &segment) in body.iter().enumerate() {
+            let index = obstacles.index(segment);
+            let free_at = body.len() - i + usize::from(growing);
+            obstacles.free_at[index] = obstacles.free_at[index].max(free_at);
+        }
+
+        obstacles
+    }
+
+    /// Blocks the bodies of `opponents` as they will be `elapsed` ticks from
+    /// now, assuming they keep moving like the snake's own body. Right now,
+    /// the cells next to their heads are blocked too, so the snake does not
+    /// risk a head-on collision.
+    fn add_opponents(&mut self, board: &BoardConfig, opponents: &[&Snake], elapsed: usize) {
+        for opponent in opponents {
+            let body = opponent.body();
+            for (i, &segment) in body.iter().enumerate() {
+                let index = self.index(segment);
+                let free_at =
+                    (body.len() - i + usize::from(opponent.has_eaten)).saturating_sub(elapsed);
+                self.free_at[index] = self.free_at[index].max(free_at);
+            }
+
+            if elapsed == 0 {
+                for direction in DIRECTIONS {
+                    let next = board.next_position(body[0], direction);
+                    if !board.is_wall(next) {
+                        let index = self.index(next);
+                        self.free_at[index] = self.free_at[index].max(2);
+                    }
+                }
+            }
+        }
+    }
+
+    fn index(&self, cell: Coordinate) -> usize {
+        (cell.1 * self.width + cell.0) as usize
+    }
+
+    /// Whether the head can enter `cell` on tick `time` from now.
+    fn is_free(&self, board: &BoardConfig, cell: Coordinate, time: usize) -> bool {
+        !board.is_wall(cell) && self.free_at[self.index(cell)] <= time
+    }
+}
+
+/// Breadth-first search for the shortest sequence of moves taking the head to
+/// `target`, avoiding the walls and every body segment that is still there
+/// when the head arrives.
+fn shortest_path(
+    board: &BoardConfig,
+    obstacles: &Obstacles,
+    head: Coordinate,
+    current_direction: Direction,
+    target: Coordinate,
+) -> Option<Vec<Direction>> {
+    let mut came_from: Vec<Option<(Coordinate, Direction)>> = vec![None; obstacles.free_at.len()];
+    let mut visited = vec![false; obstacles.free_at.len()];
+    let mut queue = VecDeque::from([(head, 0)]);
+    visited[obstacles.index(head)] = true;
+
+    while let Some((cell, time)) = queue.pop_front() {
+        for direction in DIRECTIONS
+            .into_iter()
+            // The snake cannot reverse, turning back is ignored
+            .filter(|&direction| time > 0 || direction != current_direction.opposite())
+        {
+            let next = board.next_position(cell, direction);
+            if !obstacles.is_free(board, next, time + 1) || visited[obstacles.index(next)] {
+                continue;
+            }
+
+            visited[obstacles.index(next)] = true;
+            came_from[obstacles.index(next)] = Some((cell, direction));
+
+            if next == target {
+                let mut path = Vec::new();
+                let mut cell = next;
+                while let Some((previous, direction)) = came_from[obstacles.index(cell)] {
+                    path.push(direction);
+                    cell = previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            queue.push_back((next, time + 1));
+        }
+    }
+
+    None
+}
+
+/// Plays `path` on a copy of the snake, eating at its end when `eats` is set,
+/// and checks that the snake can still reach its tail afterwards.
+fn can_reach_tail_after(
+    board: &BoardConfig,
+    body: &[Coordinate],
+    growing: bool,
+    opponents: &[&Snake],
+    path: &[Direction],
+    eats: bool,
+) -> bool {
+    let mut head = body[0];
+    let mut moved_body: Vec<Coordinate> = path
+        .iter()
+        .map(|&direction| {
+            head = board.next_position(head, direction);
+            head
+        })
+        .collect();
+    moved_body.reverse();
+    moved_body.extend_from_slice(body);
+    moved_body.truncate(body.len() + usize::from(growing));
+
+    let tail = moved_body[moved_body.len() - 1];
+    if tail == head {
+        return true;
+    }
+
+    // The snake grows on the tick after eating
+    let mut obstacles = Obstacles::new(board, &moved_body, eats);
+    obstacles.add_opponents(board, opponents, path.len());
+    shortest_path(board, &obstacles, head, path[path.len() - 1], tail).is_some()
+}
+
+/// The safe move leading to the largest area the head can still reach.
+fn roomiest_direction(
+    board: &BoardConfig,
+    obstacles: &Obstacles,
+    head: Coordinate,
+    direction: Direction,
+) -> Direction {
+    DIRECTIONS
+        .into_iter()
+        .filter(|&next_direction| next_direction != direction.opposite())
+        .filter(|&next_direction| {
+            obstacles.is_free(board, board.next_position(head, next_direction), 1)
+        })
+        .max_by_key(|&next_direction| {
+            reachable_area(board, obstacles, board.next_position(head, next_direction))
+        })
+        .unwrap_or(direction)
+}
+
+/// Number of cells the head can reach after entering `start` on the next tick.
+fn reachable_area(board: &BoardConfig, obstacles: &Obstacles, start: Coordinate) -> usize {
+    let mut visited = vec![false; obstacles.free_at.len()];
+    let mut queue = VecDeque::from([(start, 1)]);
+    visited[obstacles.index(start)] = true;
+    let mut area = 1;
+
+    while let Some((cell, time)) = queue.pop_front() {
+        for direction in DIRECTIONS {
+            let next = board.next_position(cell, direction);
+            if obstacles.is_free(board, next, time + 1) && !visited[obstacles.index(next)] {
+                visited[obstacles.index(next)] = true;
+                area += 1;
+                queue.push_back((next, time + 1));
+            }
+        }
+    }
+
+    area
+}
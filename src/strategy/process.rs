@@ -0,0 +1,214 @@
+use std::{
+    env,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::Receiver,
+    time::{Duration, Instant},
+};
+
+use crate::direction::Direction;
+
+use super::{
+    protocol::{parse_reply, read_lines, StateFrame},
+    GameView, Strategy,
+};
+
+/// Lets a bot written in any language steer the snake.
+///
+/// The bot runs as a child process. Every tick it is sent a [`StateFrame`] as
+/// a line of JSON on its standard input and has `timeout` to answer with a
+/// direction on its standard output (see [`parse_reply`]). When it does not
+/// answer in time, or the answer cannot be read, the snake keeps going
+/// straight. Late answers are dropped rather than applied to a later tick:
+/// those still queued when the next frame is sent, and those that echo the
+/// tick of an earlier frame.
+pub struct ProcessStrategy {
+    name: String,
+    child: Option<Child>,
+    stdin: Option<ChildStdin>,
+    replies: Option<Receiver<String>>,
+    timeout: Duration,
+    tick: u64,
+    error: Option<String>,
+}
+
+impl ProcessStrategy {
+    /// Starts `program` with `args`. A bot that cannot be started is reported
+    /// by [`ProcessStrategy::error`] and leaves the snake going straight.
+    pub fn new(program: &str, args: &[String], timeout: Duration) -> Self {
+        let mut strategy = Self::stopped(program, timeout);
+
+        let spawned = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            // The bot must not draw over the game
+            .stderr(Stdio::null())
+            .spawn();
+
+        match spawned {
+            Ok(mut child) => {
+                let stdout = child.stdout.take().expect("stdout is piped");
+                strategy.stdin = child.stdin.take();
+                strategy.replies = Some(read_lines(stdout));
+                strategy.child = Some(child);
+            }
+            Err(error) => strategy.error = Some(format!("could not start {}: {}", program, error)),
+        }
+
+        strategy
+    }
+
+    /// Splits `command_line` into the program and its arguments and starts
+    /// it, see [`ProcessStrategy::new`]. Words are separated by whitespace;
+    /// quote a word in `'` or `"` to keep its spaces, or escape a single
+    /// character with `\`.
+    pub fn from_command_line(command_line: &str, timeout: Duration) -> Self {
+        match split_command_line(command_line) {
+            Ok(words) => Self::new(&words[0], &words[1..], timeout),
+            Err(error) => {
+                let mut strategy = Self::stopped(command_line, timeout);
+                strategy.error = Some(error);
+                strategy
+            }
+        }
+    }
+
+    /// Checks that `command_line` names a program that can be started,
+    /// without starting it, and returns the program's path.
+    pub fn check_command_line(command_line: &str) -> Result<PathBuf, String> {
+        let words = split_command_line(command_line)?;
+        find_program(&words[0]).ok_or_else(|| format!("could not find bot program {}", words[0]))
+    }
+
+    /// Why the bot stopped playing, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// A bot that is not running (yet).
+    fn stopped(program: &str, timeout: Duration) -> Self {
+        Self {
+            name: format!("Bot ({})", program),
+            child: None,
+            stdin: None,
+            replies: None,
+            timeout,
+            tick: 0,
+            error: None,
+        }
+    }
+
+    fn ask(&mut self, view: &GameView) -> Option<Direction> {
+        let (stdin, replies) = (self.stdin.as_mut()?, self.replies.as_ref()?);
+
+        // Whatever is still queued answered an earlier tick too late
+        while replies.try_recv().is_ok() {}
+
+        let frame = serde_json::to_string(&StateFrame::new(view, self.tick)).ok()?;
+        if let Err(error) = writeln!(stdin, "{}", frame).and_then(|_| stdin.flush()) {
+            self.error = Some(format!("bot stopped reading: {}", error));
+            self.stdin = None;
+            return None;
+        }
+
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            let line = replies
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .ok()?;
+            let reply = parse_reply(&line)?;
+            // Answers to an earlier frame that came in after the drain above
+            if reply.tick.is_none_or(|tick| tick == self.tick) {
+                return Some(reply.direction);
+            }
+        }
+    }
+}
+
+impl Strategy for ProcessStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        self.tick += 1;
+        self.ask(view).unwrap_or(view.snake.direction)
+    }
+}
+
+impl Drop for ProcessStrategy {
+    fn drop(&mut self) {
+        // Closing stdin lets well-behaved bots exit on their own
+        self.stdin = None;
+        if let Some(child) = self.child.as_mut() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Splits a command line into words, honouring quotes and backslashes like a
+/// shell does, though without any expansion.
+fn split_command_line(command_line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = command_line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some(quoted) if quoted == c => break,
+                        Some('\\') if c == '"' => word.extend(chars.next()),
+                        Some(quoted) => word.push(quoted),
+                        None => return Err(format!("unterminated {} in bot command", c)),
+                    }
+                }
+            }
+            '\\' => word.get_or_insert_with(String::new).extend(chars.next()),
+            c if c.is_whitespace() => words.extend(word.take()),
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+
+    if words.is_empty() {
+        return Err("the bot command is empty".to_string());
+    }
+    Ok(words)
+}
+
+/// Resolves `program` the way starting it would: as a path when it contains
+/// a separator, otherwise by searching `PATH`.
+fn find_program(program: &str) -> Option<PathBuf> {
+    if Path::new(program).components().count() > 1 {
+        return is_executable(Path::new(program)).then(|| PathBuf::from(program));
+    }
+
+    let names = if cfg!(windows) {
+        vec![program.to_string(), format!("{}.exe", program)]
+    } else {
+        vec![program.to_string()]
+    };
+    env::split_paths(&env::var_os("PATH")?)
+        .flat_map(|directory| names.iter().map(move |name| directory.join(name)))
+        .find(|path| is_executable(path))
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
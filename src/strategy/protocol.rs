@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    io::{BufRead, BufReader, Read},
+    str::FromStr,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+use crate::{board::BoardConfig, direction::Direction, game::GameDifficulty, snake::Snake};
+
+use super::GameView;
+
+/// Version of the protocol spoken with bots connecting over TCP.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// How the game waits for a bot connected over TCP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BotMode {
+    /// Every tick waits up to the bot timeout (`--bot-timeout`) for an answer.
+    /// When the bot misses it the snake keeps its direction, and the late
+    /// reply, which echoes the earlier tick, is dropped.
+    #[default]
+    Lockstep,
+    /// The game runs at its own pace and plays the last move the bot sent.
+    RealTime,
+}
+
+impl FromStr for BotMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "lockstep" => Ok(BotMode::Lockstep),
+            "realtime" | "real-time" => Ok(BotMode::RealTime),
+            _ => Err(format!("unknown bot mode '{}'", value)),
+        }
+    }
+}
+
+impl fmt::Display for BotMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BotMode::Lockstep => write!(f, "lockstep"),
+            BotMode::RealTime => write!(f, "real-time"),
+        }
+    }
+}
+
+/// First line sent to a bot connecting over TCP, right after it connected
+/// and before any [`StateFrame`]:
+///
+/// ```text
+/// {"version":2,"mode":"Lockstep","board":{"width":40,"height":20,"topology":"Walled"},"difficulty":"MEDIUM","tick_ms":100}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Handshake {
+    pub version: u32,
+    pub mode: BotMode,
+    pub board: BoardConfig,
+    pub difficulty: GameDifficulty,
+    /// Milliseconds between two ticks.
+    pub tick_ms: u64,
+}
+
+impl Handshake {
+    pub fn new(mode: BotMode, board: BoardConfig, difficulty: GameDifficulty) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            mode,
+            board,
+            difficulty,
+            tick_ms: difficulty.tick_duration().as_millis() as u64,
+        }
+    }
+}
+
+/// State of the game sent to an external bot every tick, as one line of JSON:
+///
+/// ```text
+/// {"tick":1,"board":{"width":40,"height":20,"topology":"Walled"},"snake":[[20,10]],"direction":"Right","food":[7,3],"score":0}
+/// ```
+///
+/// `snake` lists the cells of the snake from head to tail as `[x, y]` pairs.
+/// The border cells are `x = 0`, `x = width - 1`, `y = 0` and `y = height - 1`.
+/// In a versus game `opponents` lists the other snakes the same way; it is
+/// left out when playing alone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateFrame {
+    pub tick: u64,
+    pub board: BoardConfig,
+    pub snake: Vec<[i32; 2]>,
+    pub direction: Direction,
+    pub food: [i32; 2],
+    pub score: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub opponents: Vec<Vec<[i32; 2]>>,
+}
+
+impl StateFrame {
+    pub fn new(view: &GameView, tick: u64) -> Self {
+        Self {
+            tick,
+            board: *view.board,
+            snake: cells(view.snake),
+            direction: view.snake.direction,
+            food: [view.food.0, view.food.1],
+            score: view.score,
+            opponents: view.opponents.iter().map(|snake| cells(snake)).collect(),
+        }
+    }
+}
+
+fn cells(snake: &Snake) -> Vec<[i32; 2]> {
+    snake
+        .body()
+        .iter()
+        .map(|segment| [segment.0, segment.1])
+        .collect()
+}
+
+/// A bot's answer to a [`StateFrame`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reply {
+    /// The tick of the frame the bot answered, if it echoed it.
+    pub tick: Option<u64>,
+    pub direction: Direction,
+}
+
+/// Reads a bot's reply: a direction name such as `up` or `Left`, its first
+/// letter, a JSON string (`"Up"`) or an object with a `direction` field
+/// (`{"direction":"Up"}`). The tick of the answered frame can be echoed
+/// before the name (`12 up`) or in a `tick` field
+/// (`{"tick":12,"direction":"Up"}`). Returns `None` for anything else.
+pub fn parse_reply(line: &str) -> Option<Reply> {
+    let line = line.trim();
+
+    let (tick, name) = match serde_json::from_str::<serde_json::Value>(line) {
+        Ok(serde_json::Value::String(name)) => (None, name),
+        Ok(value) => (
+            value["tick"].as_u64(),
+            value["direction"].as_str()?.to_string(),
+        ),
+        Err(_) => match line.split_once(char::is_whitespace) {
+            Some((tick, name)) => (Some(tick.parse().ok()?), name.trim().to_string()),
+            None => (None, line.to_string()),
+        },
+    };
+
+    let direction = match name.to_ascii_lowercase().as_str() {
+        "up" | "u" => Direction::Up,
+        "down" | "d" => Direction::Down,
+        "left" | "l" => Direction::Left,
+        "right" | "r" => Direction::Right,
+        _ => return None,
+    };
+    Some(Reply { tick, direction })
+}
+
+/// Reads the direction of a bot's reply, see [`parse_reply`].
+pub fn parse_move(line: &str) -> Option<Direction> {
+    parse_reply(line).map(|reply| reply.direction)
+}
+
+/// Reads `reader` line by line on a background thread. The returned channel
+/// disconnects when the reader is closed.
+pub(crate) fn read_lines<R: Read + Send + 'static>(reader: R) -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
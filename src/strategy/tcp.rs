@@ -0,0 +1,183 @@
+use std::{
+    io::{self, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError},
+    time::{Duration, Instant},
+};
+
+use crate::direction::Direction;
+
+use super::{
+    protocol::{parse_reply, read_lines, BotMode, Handshake, StateFrame},
+    GameView, Strategy,
+};
+
+/// Longest a state frame may take to send before the bot counts as gone.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Listens for bots connecting over TCP. Every connection plays one game.
+#[derive(Debug)]
+pub struct BotServer {
+    listener: TcpListener,
+}
+
+impl BotServer {
+    pub fn bind<A: ToSocketAddrs>(address: A) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(address)?,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Waits for the next bot to connect, see [`TcpStrategy::new`].
+    pub fn accept(&self, handshake: &Handshake, timeout: Duration) -> io::Result<TcpStrategy> {
+        self.listener.set_nonblocking(false)?;
+        let (stream, _) = self.listener.accept()?;
+        TcpStrategy::new(stream, handshake, timeout)
+    }
+
+    /// Accepts a bot that is already waiting to connect, if any.
+    pub fn try_accept(
+        &self,
+        handshake: &Handshake,
+        timeout: Duration,
+    ) -> io::Result<Option<TcpStrategy>> {
+        self.listener.set_nonblocking(true)?;
+        match self.listener.accept() {
+            Ok((stream, _)) => TcpStrategy::new(stream, handshake, timeout).map(Some),
+            Err(error) if error.kind() == io::ErrorKind::WouldBlock => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Lets a bot connected over TCP steer the snake.
+///
+/// As soon as it connects the bot is sent a [`Handshake`], then every tick a
+/// [`StateFrame`], each as one line of JSON, and answers with directions
+/// as described in [`parse_reply`]. In [`BotMode::Lockstep`] every tick waits
+/// up to the timeout for the bot's answer and keeps straight when there is
+/// none; in [`BotMode::RealTime`] the game does not wait and
+/// plays the most recent move the bot sent since the previous tick, keeping
+/// straight when there is none. Once the bot disconnects the snake keeps
+/// going straight.
+pub struct TcpStrategy {
+    name: String,
+    stream: Option<TcpStream>,
+    replies: Receiver<String>,
+    mode: BotMode,
+    timeout: Duration,
+    tick: u64,
+    error: Option<String>,
+}
+
+impl TcpStrategy {
+    /// Plays with the bot on `stream`, sending it `handshake` right away.
+    /// `timeout` is how long a lockstep tick waits for the bot's answer.
+    pub fn new(stream: TcpStream, handshake: &Handshake, timeout: Duration) -> io::Result<Self> {
+        stream.set_nonblocking(false)?;
+        stream.set_nodelay(true)?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+
+        let mut strategy = Self {
+            name: format!("Bot ({})", stream.peer_addr()?),
+            replies: read_lines(stream.try_clone()?),
+            stream: Some(stream),
+            mode: handshake.mode,
+            timeout,
+            tick: 0,
+            error: None,
+        };
+        if let Ok(line) = serde_json::to_string(handshake) {
+            strategy.send(&line);
+        }
+        Ok(strategy)
+    }
+
+    pub fn mode(&self) -> BotMode {
+        self.mode
+    }
+
+    /// Why the bot stopped playing, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn send(&mut self, line: &str) -> Option<()> {
+        let stream = self.stream.as_mut()?;
+        if let Err(error) = writeln!(stream, "{}", line).and_then(|_| stream.flush()) {
+            self.error = Some(format!("bot disconnected: {}", error));
+            self.stream = None;
+            return None;
+        }
+        Some(())
+    }
+
+    fn ask(&mut self, view: &GameView) -> Option<Direction> {
+        self.send(&serde_json::to_string(&StateFrame::new(view, self.tick)).ok()?)?;
+        let deadline = Instant::now() + self.timeout;
+
+        match self.mode {
+            BotMode::Lockstep => loop {
+                match self
+                    .replies
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                {
+                    Ok(line) => match parse_reply(&line) {
+                        // An answer to an earlier frame
+                        Some(reply) if reply.tick.is_some_and(|tick| tick != self.tick) => {}
+                        reply => break reply.map(|reply| reply.direction),
+                    },
+                    // A stalled bot must not freeze the game
+                    Err(RecvTimeoutError::Timeout) => break None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        self.error = Some("bot disconnected".to_string());
+                        self.stream = None;
+                        break None;
+                    }
+                }
+            },
+            BotMode::RealTime => {
+                let mut direction = None;
+                loop {
+                    match self.replies.try_recv() {
+                        Ok(line) => match parse_reply(&line) {
+                            // Answers to frames before the previous one are stale
+                            Some(reply) if reply.tick.is_some_and(|tick| tick + 1 < self.tick) => {}
+                            reply => direction = reply.map(|reply| reply.direction).or(direction),
+                        },
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => {
+                            self.error = Some("bot disconnected".to_string());
+                            self.stream = None;
+                            break;
+                        }
+                    }
+                }
+                direction
+            }
+        }
+    }
+}
+
+impl Strategy for TcpStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn next_direction(&mut self, view: &GameView) -> Direction {
+        self.tick += 1;
+        self.ask(view).unwrap_or(view.snake.direction)
+    }
+}
+
+impl Drop for TcpStrategy {
+    fn drop(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    board::BoardConfig, coordinate::Coordinate, direction::Direction, food::Food,
+    simulation::DeathCause, snake::Snake, strategy::GameView,
+};
+
+/// Most snakes that can share one board.
+pub const MAX_PLAYERS: usize = 5;
+
+/// One snake of a versus game and how it is doing.
+#[derive(Debug)]
+pub struct Player {
+    snake: Snake,
+    score: u32,
+    death_cause: Option<DeathCause>,
+}
+
+impl Player {
+    pub fn snake(&self) -> &Snake {
+        &self.snake
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn death_cause(&self) -> Option<DeathCause> {
+        self.death_cause
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.death_cause.is_none()
+    }
+}
+
+/// The result of advancing a versus game by a single tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersusOutcome {
+    /// Every snake moved without eating anything.
+    Moved,
+    /// A snake ate the food and will grow on the next tick.
+    Ate,
+    /// At least one snake died, but the game goes on.
+    Died,
+    /// The game is decided, see [`VersusSimulation::winner`]. Further calls
+    /// to `step` keep returning this outcome.
+    Over,
+}
+
+/// Headless engine for several snakes competing for the same food on one
+/// board, like [`Simulation`](crate::simulation::Simulation) is for one.
+///
+/// Every snake moves at the same time. A snake dies when its head runs into
+/// a wall, its own body or the body of another snake; when two heads meet,
+/// on the same cell or by passing through each other, both snakes die. Dead
+/// snakes are taken off the board. The game is over once at most one snake
+/// is left, which wins; when the last snakes die together it is a draw.
+#[derive(Debug)]
+pub struct VersusSimulation {
+    board: BoardConfig,
+    seed: u64,
+    rng: ChaCha8Rng,
+    players: Vec<Player>,
+    food: Food,
+    ticks: u64,
+    over: bool,
+    winner: Option<usize>,
+}
+
+impl VersusSimulation {
+    /// Starts a game for `player_count` snakes, between 1 and [`MAX_PLAYERS`],
+    /// spread over the board at equal distances from its corners, the fifth
+    /// one in the middle.
+    pub fn new(board: BoardConfig, player_count: usize, seed: u64) -> Self {
+        assert!(
+            (1..=MAX_PLAYERS).contains(&player_count),
+            "a versus game has 1 to {} players",
+            MAX_PLAYERS
+        );
+
+        let left = board.width / 4;
+        let right = board.width - 1 - board.width / 4;
+        let top = board.height / 3;
+        let bottom = board.height - 1 - board.height / 3;
+        let spawns = [
+            (Coordinate(left, top), Direction::Right),
+            (Coordinate(right, bottom), Direction::Left),
+            (Coordinate(left, bottom), Direction::Right),
+            (Coordinate(right, top), Direction::Left),
+            (board.center(), Direction::Up),
+        ];
+
+        let snakes = spawns[..player_count]
+            .iter()
+            .map(|&(position, direction)| Snake::spawn(board, position, direction))
+            .collect();
+
+        Self::with_snakes(board, snakes, seed)
+    }
+
+    /// Starts a game with the given snakes, e.g. to set up a position.
+    pub fn with_snakes(board: BoardConfig, snakes: Vec<Snake>, seed: u64) -> Self {
+        let players: Vec<Player> = snakes
+            .into_iter()
+            .map(|snake| Player {
+                snake,
+                score: 0,
+                death_cause: None,
+            })
+            .collect();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let food = Food::place(
+            &board,
+            players.iter().flat_map(|player| player.snake.body()),
+            &mut rng,
+        )
+        .expect("a new board has free cells");
+
+        Self {
+            board,
+            seed,
+            rng,
+            players,
+            food,
+            ticks: 0,
+            over: false,
+            winner: None,
+        }
+    }
+
+    /// Advances the game by one tick, turning each snake by the input at its
+    /// index first. Missing inputs and inputs of dead snakes are ignored.
+    pub fn step(&mut self, inputs: &[Option<Direction>]) -> VersusOutcome {
+        if self.over {
+            return VersusOutcome::Over;
+        }
+
+        let previous_heads: Vec<Coordinate> = self
+            .players
+            .iter()
+            .map(|player| player.snake.head_position())
+            .collect();
+
+        for (index, player) in self.players.iter_mut().enumerate() {
+            if !player.is_alive() {
+                continue;
+            }
+            if let Some(direction) = inputs.get(index).copied().flatten() {
+                player.snake.change_direction(direction);
+            }
+            player.snake.move_forward();
+        }
+        self.ticks += 1;
+
+        // Collisions are decided on the new positions of every snake that
+        // was alive at the start of the tick, before any of them is removed
+        let deaths: Vec<(usize, DeathCause)> = (0..self.players.len())
+            .filter(|&index| self.players[index].is_alive())
+            .filter_map(|index| {
+                self.collision(index, &previous_heads)
+                    .map(|cause| (index, cause))
+            })
+            .collect();
+        for &(index, cause) in &deaths {
+            self.players[index].death_cause = Some(cause);
+        }
+
+        let mut ate = false;
+        let food = self.food.position;
+        if let Some(player) = self
+            .players
+            .iter_mut()
+            .find(|player| player.is_alive() && player.snake.head_position() == food)
+        {
+            player.snake.grow();
+            player.score += 1;
+            ate = true;
+
+            match Food::place(
+                &self.board,
+                self.players
+                    .iter()
+                    .filter(|player| player.is_alive())
+                    .flat_map(|player| player.snake.body()),
+                &mut self.rng,
+            ) {
+                Some(food) => self.food = food,
+                // No free cell is left, the highest score takes the game
+                None => {
+                    self.finish(true);
+                    return VersusOutcome::Over;
+                }
+            }
+        }
+
+        let alive = self
+            .players
+            .iter()
+            .filter(|player| player.is_alive())
+            .count();
+        if alive == 0 || (alive == 1 && self.players.len() > 1) {
+            self.finish(false);
+            return VersusOutcome::Over;
+        }
+
+        if !deaths.is_empty() {
+            VersusOutcome::Died
+        } else if ate {
+            VersusOutcome::Ate
+        } else {
+            VersusOutcome::Moved
+        }
+    }
+
+    /// Takes `player` out of the game, e.g. when they disconnect. The game is
+    /// decided on the next call to `step` if that leaves a single snake.
+    pub fn eliminate(&mut self, player: usize) {
+        if self.players[player].is_alive() {
+            self.players[player].death_cause = Some(DeathCause::Disconnected);
+        }
+    }
+
+    /// What the snake at `index` ran into on this tick, if anything.
+    /// `previous_heads` are the heads of every snake before they moved.
+    fn collision(&self, index: usize, previous_heads: &[Coordinate]) -> Option<DeathCause> {
+        let snake = &self.players[index].snake;
+        if snake.collides_with_wall() {
+            return Some(DeathCause::Wall);
+        }
+        if snake.collides_with_self() {
+            return Some(DeathCause::SelfCollision);
+        }
+
+        let head = snake.head_position();
+        let mut cause = None;
+        for (other_index, other) in self.players.iter().enumerate() {
+            if other_index == index || !other.is_alive() {
+                continue;
+            }
+
+            let other_body = other.snake.body();
+            let passed_through =
+                previous_heads[index] == other_body[0] && previous_heads[other_index] == head;
+            if other_body[0] == head || passed_through {
+                return Some(DeathCause::HeadOn);
+            }
+            if other_body.contains(&head) {
+                cause = Some(DeathCause::OtherSnake);
+            }
+        }
+
+        cause
+    }
+
+    /// Ends the game and decides the winner among the snakes still alive:
+    /// the only one left, or with `by_score`, the one with the best score.
+    fn finish(&mut self, by_score: bool) {
+        self.over = true;
+
+        let alive: Vec<usize> = (0..self.players.len())
+            .filter(|&index| self.players[index].is_alive())
+            .collect();
+        self.winner = match alive.as_slice() {
+            [index] => Some(*index),
+            _ if by_score => {
+                let best = alive.iter().map(|&index| self.players[index].score).max();
+                let mut leaders = alive
+                    .iter()
+                    .filter(|&&index| Some(self.players[index].score) == best);
+                match (leaders.next(), leaders.next()) {
+                    (Some(&index), None) => Some(index),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+    }
+
+    pub fn board(&self) -> &BoardConfig {
+        &self.board
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn food(&self) -> &Food {
+        &self.food
+    }
+
+    /// Read-only view of the game for a [`Strategy`](crate::strategy::Strategy)
+    /// steering the snake of `player`, with every other living snake as an
+    /// opponent.
+    pub fn view(&self, player: usize) -> GameView<'_> {
+        GameView {
+            board: &self.board,
+            snake: &self.players[player].snake,
+            food: self.food.position,
+            score: self.players[player].score,
+            opponents: self
+                .players
+                .iter()
+                .enumerate()
+                .filter(|&(index, other)| index != player && other.is_alive())
+                .map(|(_, other)| &other.snake)
+                .collect(),
+        }
+    }
+
+    /// Number of ticks the game has been advanced so far.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.over
+    }
+
+    /// Index of the player who won, `None` while the game is running or
+    /// when it ended in a draw.
+    pub fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+}
@@ -0,0 +1,359 @@
+use crate::board::BoardConfig;
+use crate::game::{draw_screen, pause_menu, Audio, GameDifficulty, PauseChoice, GAME_OVER_DELAY};
+use crate::input::{Controls, SharedKeyboard};
+use crate::net::{
+    protocol::{SpectatorFrame, SpectatorMessage},
+    Broadcaster,
+};
+use crate::screen::{player_line, BoardStyle, BoardWidget, GameScreen, Overlay};
+use crate::simulation::DeathCause;
+use crate::strategy::{ClumsyStrategy, GreedyStrategy, PathfindingStrategy, Strategy};
+use crate::versus::{VersusOutcome, VersusSimulation, MAX_PLAYERS};
+use serde::{Deserialize, Serialize};
+use std::{thread, time};
+use tui::{backend::Backend, style::Color, text::Spans, Terminal};
+
+/// Color of each player's snake, in player order.
+pub const PLAYER_COLORS: [Color; MAX_PLAYERS] = [
+    Color::LightGreen,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+    Color::LightYellow,
+];
+
+/// Who steers a snake in a versus game.
+pub enum Controller {
+    /// A player at the keyboard.
+    Keyboard(Controls),
+    /// The computer, playing the given strategy.
+    Computer(Box<dyn Strategy>),
+}
+
+/// A player in a versus game.
+pub struct VersusPlayer {
+    pub name: String,
+    pub controller: Controller,
+}
+
+impl VersusPlayer {
+    pub fn human(name: &str, controls: Controls) -> Self {
+        Self {
+            name: name.to_string(),
+            controller: Controller::Keyboard(controls),
+        }
+    }
+
+    pub fn computer(name: &str, strategy: Box<dyn Strategy>) -> Self {
+        Self {
+            name: name.to_string(),
+            controller: Controller::Computer(strategy),
+        }
+    }
+
+    /// How the player steers, as shown next to their score.
+    fn controller_name(&self) -> &str {
+        match &self.controller {
+            Controller::Keyboard(controls) => controls.name(),
+            Controller::Computer(strategy) => strategy.name(),
+        }
+    }
+}
+
+/// A computer opponent as strong as the difficulty: on Easy it plays
+/// [`GreedyStrategy`] and misses one turn in four, on Medium
+/// [`PathfindingStrategy`] missing one turn in ten, and on Hard
+/// [`PathfindingStrategy`] without any lapses.
+pub fn computer_opponent(difficulty: GameDifficulty, seed: u64) -> Box<dyn Strategy> {
+    match difficulty {
+        GameDifficulty::EASY => Box::new(ClumsyStrategy::new(Box::new(GreedyStrategy), 0.25, seed)),
+        GameDifficulty::MEDIUM => Box::new(ClumsyStrategy::new(
+            Box::new(PathfindingStrategy),
+            0.1,
+            seed,
+        )),
+        GameDifficulty::HARD => Box::new(PathfindingStrategy),
+    }
+}
+
+/// How one player did in a finished versus game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSummary {
+    pub name: String,
+    pub score: u32,
+    /// Number of segments of the snake when the game ended.
+    pub length: usize,
+    pub death_cause: Option<DeathCause>,
+}
+
+/// Statistics of a finished versus game, as shown on the winner screen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VersusSummary {
+    pub players: Vec<PlayerSummary>,
+    /// Index of the player who won, `None` for a draw.
+    pub winner: Option<usize>,
+    pub ticks: u64,
+    /// In-game time, i.e. the number of ticks times the tick duration.
+    pub time_played: time::Duration,
+    pub seed: u64,
+}
+
+/// How a versus game run in the terminal came to an end.
+#[derive(Debug, Clone)]
+pub enum VersusExit {
+    /// The game was decided.
+    Finished(VersusSummary),
+    /// The players asked for a new game with the same settings from the pause menu.
+    Restart,
+    /// The players left the game from the pause menu.
+    QuitToMenu,
+}
+
+/// Terminal front end for a [`VersusSimulation`] where several players share
+/// one keyboard, e.g. WASD against the arrow keys, or play against computer
+/// opponents. Versus games are not recorded as replays and do not enter the
+/// high score table.
+pub struct VersusGame {
+    simulation: VersusSimulation,
+    players: Vec<VersusPlayer>,
+    keyboard: SharedKeyboard,
+    game_difficulty: GameDifficulty,
+    audio: Audio,
+    broadcaster: Option<Broadcaster>,
+    board_style: BoardStyle,
+}
+
+impl VersusGame {
+    pub fn new(
+        game_difficulty: GameDifficulty,
+        players: Vec<VersusPlayer>,
+        sound_enabled: bool,
+        music_enabled: bool,
+        seed: u64,
+        board: BoardConfig,
+    ) -> Self {
+        let controls: Vec<Controls> = players
+            .iter()
+            .filter_map(|player| match player.controller {
+                Controller::Keyboard(controls) => Some(controls),
+                Controller::Computer(_) => None,
+            })
+            .collect();
+
+        Self {
+            simulation: VersusSimulation::new(board, players.len(), seed),
+            keyboard: SharedKeyboard::new(&controls),
+            players,
+            game_difficulty,
+            audio: Audio::new(sound_enabled, music_enabled),
+            broadcaster: None,
+            board_style: BoardStyle::default(),
+        }
+    }
+
+    /// Publishes every tick of the game to the spectators of `broadcaster`.
+    pub fn broadcast_to(&mut self, broadcaster: Broadcaster) {
+        self.broadcaster = Some(broadcaster);
+    }
+
+    /// Draws the board in `board_style` instead of the default one.
+    pub fn set_board_style(&mut self, board_style: BoardStyle) {
+        self.board_style = board_style;
+    }
+
+    /// Plays the game in `terminal` until it is decided or the players
+    /// leave it from the pause menu.
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> VersusExit {
+        self.audio.start_music();
+
+        loop {
+            let directions: Vec<_> = self
+                .players
+                .iter()
+                .zip(self.simulation.players())
+                .filter(|(player, _)| matches!(player.controller, Controller::Keyboard(_)))
+                .map(|(_, state)| state.snake().direction)
+                .collect();
+            self.keyboard.poll_input(&directions);
+
+            if self.keyboard.take_pause_request() {
+                self.publish_frame(true);
+                let screen = self.screen();
+                if let Some(choice) = pause_menu(&mut self.audio, terminal, &screen) {
+                    publish_game_over(self.broadcaster.as_ref(), None);
+                    self.audio.stop_music();
+                    return match choice {
+                        PauseChoice::Restart => VersusExit::Restart,
+                        PauseChoice::QuitToMenu => VersusExit::QuitToMenu,
+                    };
+                }
+
+                // Show the frozen board for a whole tick before moving on again
+                draw_screen(terminal, self.screen());
+                thread::sleep(self.game_difficulty.tick_duration());
+                continue;
+            }
+
+            let mut inputs = Vec::with_capacity(self.players.len());
+            let mut keyboard_player = 0;
+            for (index, player) in self.players.iter_mut().enumerate() {
+                inputs.push(match &mut player.controller {
+                    Controller::Keyboard(_) => {
+                        keyboard_player += 1;
+                        self.keyboard.next_turn(keyboard_player - 1)
+                    }
+                    Controller::Computer(strategy)
+                        if self.simulation.players()[index].is_alive() =>
+                    {
+                        Some(strategy.next_direction(&self.simulation.view(index)))
+                    }
+                    Controller::Computer(_) => None,
+                });
+            }
+
+            match self.simulation.step(&inputs) {
+                VersusOutcome::Moved => {}
+                VersusOutcome::Ate => self.audio.play_eat(),
+                VersusOutcome::Died => self.audio.play_death(),
+                VersusOutcome::Over => {
+                    let summary = self.summary();
+                    self.publish_frame(false);
+                    publish_game_over(self.broadcaster.as_ref(), Some(&summary));
+
+                    let shown = time::Instant::now();
+                    draw_screen(
+                        terminal,
+                        self.screen()
+                            .overlay(Overlay::message(&headline(&summary), Vec::new())),
+                    );
+
+                    if self.simulation.winner().is_some() {
+                        self.audio.play_fanfare();
+                    } else {
+                        self.audio.play_death();
+                    }
+
+                    self.audio.stop_music();
+                    thread::sleep(GAME_OVER_DELAY.saturating_sub(shown.elapsed()));
+
+                    return VersusExit::Finished(summary);
+                }
+            }
+
+            draw_screen(terminal, self.screen());
+            self.publish_frame(false);
+            thread::sleep(self.game_difficulty.tick_duration());
+        }
+    }
+
+    fn publish_frame(&self, paused: bool) {
+        if let Some(broadcaster) = &self.broadcaster {
+            let names: Vec<String> = self
+                .players
+                .iter()
+                .map(|player| player.name.clone())
+                .collect();
+            broadcaster.publish(&SpectatorMessage::Frame(SpectatorFrame::from_versus(
+                &self.simulation,
+                &names,
+                paused,
+            )));
+        }
+    }
+
+    pub fn sound_enabled(&self) -> bool {
+        self.audio.sound_enabled
+    }
+
+    pub fn music_enabled(&self) -> bool {
+        self.audio.music_enabled
+    }
+
+    pub fn simulation(&self) -> &VersusSimulation {
+        &self.simulation
+    }
+
+    fn summary(&self) -> VersusSummary {
+        VersusSummary {
+            players: self
+                .players
+                .iter()
+                .zip(self.simulation.players())
+                .map(|(player, state)| PlayerSummary {
+                    name: player.name.clone(),
+                    score: state.score(),
+                    length: state.snake().body().len(),
+                    death_cause: state.death_cause(),
+                })
+                .collect(),
+            winner: self.simulation.winner(),
+            ticks: self.simulation.ticks(),
+            time_played: self.game_difficulty.tick_duration() * self.simulation.ticks() as u32,
+            seed: self.simulation.seed(),
+        }
+    }
+
+    /// The living snakes in their players' colors, with every player's
+    /// score in the side panel.
+    fn screen(&self) -> GameScreen {
+        let mut board = BoardWidget::new(self.simulation.board(), self.board_style);
+        let mut hud = Vec::new();
+        for ((player, state), color) in self
+            .players
+            .iter()
+            .zip(self.simulation.players())
+            .zip(PLAYER_COLORS)
+        {
+            if state.is_alive() {
+                board = board.snake(state.snake().body(), state.snake().direction, color);
+            }
+            hud.push(player_line(
+                &format!("{} ({})", player.name, player.controller_name()),
+                color,
+            ));
+            hud.push(Spans::from(format!(
+                "  Score: {}{}",
+                state.score(),
+                if state.is_alive() { "" } else { " (out)" }
+            )));
+        }
+
+        let time_played = self.game_difficulty.tick_duration() * self.simulation.ticks() as u32;
+        let seconds = time_played.as_secs();
+        hud.push(Spans::from(""));
+        hud.push(Spans::from(format!(
+            "Time: {}:{:02}",
+            seconds / 60,
+            seconds % 60
+        )));
+        hud.push(Spans::from(""));
+        hud.push(Spans::from("P/Esc: pause"));
+
+        GameScreen::new(board.food(self.simulation.food().position), "Versus", hud)
+    }
+}
+
+/// Who won a versus game, e.g. "Ada wins!".
+pub(crate) fn headline(summary: &VersusSummary) -> String {
+    match summary.winner {
+        Some(winner) => format!("{} wins!", summary.players[winner].name),
+        None => "It's a draw!".to_string(),
+    }
+}
+
+/// Tells the spectators of a versus game who won, or with no `summary`, that
+/// the players left the game.
+pub(crate) fn publish_game_over(
+    broadcaster: Option<&Broadcaster>,
+    summary: Option<&VersusSummary>,
+) {
+    let Some(broadcaster) = broadcaster else {
+        return;
+    };
+
+    let message = match summary {
+        Some(summary) => headline(summary),
+        None => "The players left the game.".to_string(),
+    };
+    broadcaster.publish(&SpectatorMessage::GameOver { message });
+}
@@ -0,0 +1,150 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use snake::versus_game::{VersusSummary, PLAYER_COLORS};
+
+use crate::game_over_menu::GameOverItem;
+
+/// Screen shown at the end of a versus game, naming the winner and how every
+/// player did.
+pub struct WinnerMenu {
+    summary: VersusSummary,
+    /// Label of the "Restart" item, e.g. "Rematch".
+    restart_label: &'static str,
+    items: Vec<GameOverItem>,
+    state: ListState,
+}
+
+impl WinnerMenu {
+    pub fn new(summary: VersusSummary, restart_label: &'static str) -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+
+        Self {
+            summary,
+            restart_label,
+            items: vec![
+                GameOverItem::Restart,
+                GameOverItem::MainMenu,
+                GameOverItem::Quit,
+            ],
+            state,
+        }
+    }
+
+    pub fn next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn render<B: Backend>(&mut self, f: &mut Frame<B>) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(self.summary.players.len() as u16 * 2 + 6),
+                    Constraint::Min(5),
+                ]
+                .as_ref(),
+            )
+            .split(f.size());
+
+        let (headline, color) = match self.summary.winner {
+            Some(winner) => (
+                format!("{} wins!", self.summary.players[winner].name),
+                PLAYER_COLORS[winner],
+            ),
+            None => ("It's a draw!".to_string(), Color::Yellow),
+        };
+        let title = Spans::from(vec![Span::styled(
+            headline,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )]);
+
+        let mut text = Vec::new();
+        for (index, player) in self.summary.players.iter().enumerate() {
+            let outcome = match player.death_cause {
+                Some(cause) => cause.to_string(),
+                None => "Survived".to_string(),
+            };
+            text.push(Spans::from(Span::styled(
+                player.name.clone(),
+                Style::default()
+                    .fg(PLAYER_COLORS[index])
+                    .add_modifier(Modifier::BOLD),
+            )));
+            text.push(Spans::from(format!(
+                "  Score: {}   Length: {}   {}",
+                player.score, player.length, outcome
+            )));
+        }
+
+        let seconds = self.summary.time_played.as_secs();
+        text.push(Spans::from(""));
+        text.push(Spans::from(format!(
+            "Time played: {}:{:02} ({} ticks)",
+            seconds / 60,
+            seconds % 60,
+            self.summary.ticks
+        )));
+        text.push(Spans::from(format!("Seed: {}", self.summary.seed)));
+
+        let stats = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|i| {
+                let content = match i {
+                    GameOverItem::Restart => self.restart_label,
+                    GameOverItem::MainMenu => "Main Menu",
+                    GameOverItem::Quit => "Quit",
+                };
+                ListItem::new(Spans::from(content))
+            })
+            .collect();
+
+        let menu = List::new(items)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::Yellow).fg(Color::Black))
+            .highlight_symbol("> ");
+
+        f.render_widget(stats, chunks[1]);
+        f.render_stateful_widget(menu, chunks[2], &mut self.state);
+    }
+
+    pub fn get_selected(&self) -> Option<GameOverItem> {
+        self.state.selected().map(|i| self.items[i])
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        bench::{self, play_game, BenchSummary, GameOutcome, GameResult, OutputFormat},
+        board::BoardConfig,
+        simulation::DeathCause,
+        strategy::{GreedyStrategy, HamiltonianStrategy, StrategyRegistry},
+    };
+    use std::time::Duration;
+
+    fn result(score: u32, ticks: u64, outcome: GameOutcome) -> GameResult {
+        GameResult {
+            seed: 0,
+            score,
+            ticks,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_play_game_is_deterministic() {
+        let board = BoardConfig::new(20, 12).unwrap();
+        let first = play_game(&mut GreedyStrategy, board, 3, 10_000);
+        let second = play_game(&mut GreedyStrategy, board, 3, 10_000);
+
+        assert_eq!(first, second);
+        assert!(matches!(first.outcome, GameOutcome::Died(_)));
+    }
+
+    #[test]
+    fn test_play_game_times_out() {
+        let result = play_game(&mut GreedyStrategy, BoardConfig::default(), 0, 5);
+        assert_eq!(result.outcome, GameOutcome::TimedOut);
+        assert_eq!(result.ticks, 5);
+    }
+
+    #[test]
+    fn test_play_game_won() {
+        let board = BoardConfig::new(10, 8).unwrap();
+        let result = play_game(&mut HamiltonianStrategy::default(), board, 0, 100_000);
+        assert_eq!(result.outcome, GameOutcome::Won);
+        assert_eq!(result.score as usize, board.playable_area());
+    }
+
+    #[test]
+    fn test_summary_statistics() {
+        let results = [
+            result(4, 40, GameOutcome::Died(DeathCause::Wall)),
+            result(10, 100, GameOutcome::Died(DeathCause::SelfCollision)),
+            result(2, 20, GameOutcome::Died(DeathCause::Wall)),
+            result(48, 400, GameOutcome::Won),
+        ];
+        let summary = BenchSummary::new(
+            "Greedy",
+            BoardConfig::default(),
+            &results,
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(summary.games, 4);
+        assert_eq!(summary.average_score, 16.0);
+        assert_eq!(summary.median_score, 7.0);
+        assert_eq!(summary.max_score, 48);
+        assert_eq!(summary.average_ticks, 140.0);
+        assert_eq!(summary.wins, 1);
+        assert_eq!(summary.wall_deaths, 2);
+        assert_eq!(summary.self_collisions, 1);
+        assert_eq!(summary.timeouts, 0);
+        assert_eq!(summary.games_per_second, 2.0);
+    }
+
+    #[test]
+    fn test_run_strategy_unknown_name() {
+        let registry = StrategyRegistry::with_builtin();
+        assert!(
+            bench::run_strategy(&registry, "Missing", BoardConfig::default(), 0..1, 10).is_none()
+        );
+    }
+
+    #[test]
+    fn test_csv_and_json_reports() {
+        let registry = StrategyRegistry::with_builtin();
+        let board = BoardConfig::new(10, 8).unwrap();
+        let summary = bench::run_strategy(&registry, "Greedy", board, 0..3, 10_000).unwrap();
+        assert_eq!(summary.games, 3);
+
+        let mut csv = Vec::new();
+        bench::write_report(std::slice::from_ref(&summary), OutputFormat::Csv, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("strategy,width,height,mode,games,"));
+        assert!(lines[1].starts_with("Greedy,10,8,Classic,3,"));
+
+        let mut json = Vec::new();
+        bench::write_report(&[summary], OutputFormat::Json, &mut json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value[0]["strategy"], "Greedy");
+        assert_eq!(value[0]["games"], 3);
+        assert_eq!(value[0]["board"]["width"], 10);
+    }
+
+    #[test]
+    fn test_output_format_parsing() {
+        assert_eq!("csv".parse(), Ok(OutputFormat::Csv));
+        assert_eq!("JSON".parse(), Ok(OutputFormat::Json));
+        assert_eq!("table".parse(), Ok(OutputFormat::Table));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::{BoardConfig, BoardError, Topology},
+        constants::{BOARD_HEIGHT, BOARD_WIDTH},
+        coordinate::Coordinate,
+        direction::Direction,
+        simulation::{DeathCause, Simulation, StepOutcome},
+    };
+
+    #[test]
+    fn test_default_board() {
+        let board = BoardConfig::default();
+        assert_eq!(board.width, BOARD_WIDTH);
+        assert_eq!(board.height, BOARD_HEIGHT);
+        assert_eq!(
+            board.center(),
+            Coordinate(BOARD_WIDTH / 2, BOARD_HEIGHT / 2)
+        );
+    }
+
+    #[test]
+    fn test_board_too_small() {
+        assert_eq!(
+            BoardConfig::new(4, 10),
+            Err(BoardError::TooSmall {
+                width: 4,
+                height: 10
+            })
+        );
+        assert!(BoardConfig::new(5, 5).is_ok());
+    }
+
+    #[test]
+    fn test_parse_board() {
+        assert_eq!("30x15".parse(), BoardConfig::new(30, 15));
+        assert!("30".parse::<BoardConfig>().is_err());
+        assert!("axb".parse::<BoardConfig>().is_err());
+        assert!("2x2".parse::<BoardConfig>().is_err());
+    }
+
+    #[test]
+    fn test_is_wall() {
+        let board = BoardConfig::new(6, 5).unwrap();
+        assert!(board.is_wall(Coordinate(0, 2)));
+        assert!(board.is_wall(Coordinate(5, 2)));
+        assert!(board.is_wall(Coordinate(2, 4)));
+        assert!(!board.is_wall(Coordinate(1, 1)));
+        assert!(!board.is_wall(Coordinate(4, 3)));
+        assert_eq!(board.playable_area(), 12);
+    }
+
+    #[test]
+    fn test_simulation_on_tiny_board() {
+        let board = BoardConfig::new(5, 5).unwrap();
+        let mut simulation = Simulation::new(board, 0);
+        assert_eq!(simulation.snake().head_position(), Coordinate(2, 2));
+
+        let first = simulation.step(Some(Direction::Right));
+        assert_ne!(first, StepOutcome::Died(DeathCause::Wall));
+        assert_eq!(
+            simulation.step(Some(Direction::Right)),
+            StepOutcome::Died(DeathCause::Wall)
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        let walled = BoardConfig::new(6, 5).unwrap();
+        assert_eq!(walled.wrap(Coordinate(0, 2)), Coordinate(0, 2));
+
+        let board = walled.with_topology(Topology::Wrapping);
+        assert_eq!(board.wrap(Coordinate(0, 2)), Coordinate(4, 2));
+        assert_eq!(board.wrap(Coordinate(5, 2)), Coordinate(1, 2));
+        assert_eq!(board.wrap(Coordinate(2, 0)), Coordinate(2, 3));
+        assert_eq!(board.wrap(Coordinate(2, 4)), Coordinate(2, 1));
+        assert_eq!(board.wrap(Coordinate(3, 2)), Coordinate(3, 2));
+        assert!(!board.is_wall(Coordinate(0, 2)));
+        assert!(board.is_border(Coordinate(0, 2)));
+    }
+
+    #[test]
+    fn test_next_position_wraps() {
+        let board = BoardConfig::new(6, 5)
+            .unwrap()
+            .with_topology(Topology::Wrapping);
+        assert_eq!(
+            board.next_position(Coordinate(4, 2), Direction::Right),
+            Coordinate(1, 2)
+        );
+        assert_eq!(
+            board.next_position(Coordinate(2, 1), Direction::Up),
+            Coordinate(2, 3)
+        );
+    }
+
+    #[test]
+    fn test_distance() {
+        let walled = BoardConfig::default();
+        assert_eq!(walled.distance(Coordinate(1, 1), Coordinate(38, 1)), 37);
+
+        let wrapping = walled.with_topology(Topology::Wrapping);
+        assert_eq!(wrapping.distance(Coordinate(1, 1), Coordinate(38, 1)), 1);
+        assert_eq!(wrapping.distance(Coordinate(5, 1), Coordinate(5, 18)), 1);
+        assert_eq!(wrapping.distance(Coordinate(5, 5), Coordinate(8, 7)), 5);
+    }
+
+    #[test]
+    fn test_simulation_wraps_around_edges() {
+        let board = BoardConfig::default().with_topology(Topology::Wrapping);
+        let mut simulation = Simulation::new(board, 0);
+        for _ in 0..board.width * 2 {
+            if let StepOutcome::Died(cause) = simulation.step(None) {
+                panic!("snake died on a wrapping board: {:?}", cause);
+            }
+        }
+        assert!(!simulation.is_over());
+    }
+}
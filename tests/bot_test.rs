@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::BoardConfig,
+        direction::Direction,
+        simulation::Simulation,
+        strategy::{
+            protocol::{parse_move, parse_reply, Reply, StateFrame},
+            ProcessStrategy, Strategy,
+        },
+        versus::VersusSimulation,
+    };
+    use std::time::Duration;
+
+    fn shell_bot(script: &str, timeout: Duration) -> ProcessStrategy {
+        ProcessStrategy::new("sh", &["-c".to_string(), script.to_string()], timeout)
+    }
+
+    #[test]
+    fn test_parse_move() {
+        assert_eq!(parse_move("up"), Some(Direction::Up));
+        assert_eq!(parse_move("Down\n"), Some(Direction::Down));
+        assert_eq!(parse_move("L"), Some(Direction::Left));
+        assert_eq!(parse_move("\"Right\""), Some(Direction::Right));
+        assert_eq!(parse_move("{\"direction\":\"Up\"}"), Some(Direction::Up));
+        assert_eq!(parse_move("sideways"), None);
+        assert_eq!(parse_move("{\"move\":\"Up\"}"), None);
+    }
+
+    #[test]
+    fn test_parse_reply_with_tick() {
+        assert_eq!(
+            parse_reply("12 up"),
+            Some(Reply {
+                tick: Some(12),
+                direction: Direction::Up
+            })
+        );
+        assert_eq!(
+            parse_reply("{\"tick\":3,\"direction\":\"Left\"}"),
+            Some(Reply {
+                tick: Some(3),
+                direction: Direction::Left
+            })
+        );
+        assert_eq!(
+            parse_reply("down"),
+            Some(Reply {
+                tick: None,
+                direction: Direction::Down
+            })
+        );
+        assert_eq!(parse_reply("soon up"), None);
+        assert_eq!(parse_reply("12"), None);
+    }
+
+    #[test]
+    fn test_state_frame() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let frame = StateFrame::new(&simulation.view(), 1);
+
+        assert_eq!(frame.tick, 1);
+        assert_eq!(frame.board, BoardConfig::default());
+        assert_eq!(frame.snake, vec![[20, 10]]);
+        assert_eq!(frame.direction, Direction::Right);
+        assert_eq!(
+            frame.food,
+            [simulation.food().position.0, simulation.food().position.1]
+        );
+
+        let json = serde_json::to_string(&frame).unwrap();
+        assert!(!json.contains('\n'));
+        assert_eq!(serde_json::from_str::<StateFrame>(&json).unwrap(), frame);
+    }
+
+    #[test]
+    fn test_bot_steers_snake() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut bot = shell_bot("while read line; do echo up; done", Duration::from_secs(5));
+
+        assert!(bot.error().is_none());
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Up);
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Up);
+    }
+
+    #[test]
+    fn test_bot_receives_state() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut bot = shell_bot(
+            r#"read line; case "$line" in *'"tick":1,'*'"snake":[[20,10]]'*) echo left;; *) echo up;; esac"#,
+            Duration::from_secs(5),
+        );
+
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Left);
+    }
+
+    #[test]
+    fn test_answers_to_earlier_ticks_are_dropped() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut bot = shell_bot(
+            "read line; echo '0 left'; echo '1 up'; cat > /dev/null",
+            Duration::from_secs(5),
+        );
+
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Up);
+    }
+
+    #[test]
+    fn test_command_line_keeps_quoted_arguments() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut bot = ProcessStrategy::from_command_line(
+            r#"sh -c 'while read line; do echo "$line" | grep -q \"tick\" && echo down; done'"#,
+            Duration::from_secs(5),
+        );
+
+        assert!(bot.error().is_none());
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Down);
+    }
+
+    #[test]
+    fn test_check_command_line_does_not_start_the_bot() {
+        let marker = std::env::temp_dir().join(format!("snake-bot-check-{}", std::process::id()));
+        let command = format!("sh -c 'touch {}'", marker.display());
+
+        assert!(ProcessStrategy::check_command_line(&command).is_ok());
+        assert!(!marker.exists());
+        assert!(ProcessStrategy::check_command_line("snake-bot-that-does-not-exist").is_err());
+        assert!(ProcessStrategy::check_command_line("sh -c 'unterminated").is_err());
+        assert!(ProcessStrategy::check_command_line("   ").is_err());
+    }
+
+    #[test]
+    fn test_silent_bot_keeps_snake_going() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut bot = shell_bot("cat > /dev/null", Duration::from_millis(50));
+
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Right);
+        assert!(bot.error().is_none());
+    }
+
+    #[test]
+    fn test_missing_bot_keeps_snake_going() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut bot = ProcessStrategy::new("snake-bot-that-does-not-exist", &[], Duration::ZERO);
+
+        assert!(bot.error().is_some());
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Right);
+    }
+
+    #[test]
+    fn test_state_frame_lists_opponents() {
+        let simulation = VersusSimulation::new(BoardConfig::default(), 2, 0);
+        let frame = StateFrame::new(&simulation.view(0), 1);
+
+        let opponent = simulation.players()[1].snake().head_position();
+        assert_eq!(frame.opponents, vec![vec![[opponent.0, opponent.1]]]);
+        assert!(serde_json::to_string(&frame)
+            .unwrap()
+            .contains("\"opponents\""));
+
+        // Frames of single-player games stay as they were
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let json = serde_json::to_string(&StateFrame::new(&simulation.view(), 1)).unwrap();
+        assert!(!json.contains("opponents"));
+    }
+}
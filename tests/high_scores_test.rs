@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::{BoardConfig, Topology},
+        game::GameDifficulty,
+        high_scores::{self, HighScore, HighScores, MAX_HIGH_SCORES, MAX_NAME_LENGTH},
+    };
+    use std::{env, fs};
+
+    #[test]
+    fn test_scores_are_kept_per_difficulty_and_mode() {
+        let mut high_scores = HighScores::default();
+        high_scores.insert(
+            GameDifficulty::EASY,
+            Topology::Walled,
+            HighScore::new("ada", 5),
+        );
+        high_scores.insert(
+            GameDifficulty::EASY,
+            Topology::Wrapping,
+            HighScore::new("bob", 7),
+        );
+
+        let walled = high_scores.entries(GameDifficulty::EASY, Topology::Walled);
+        assert_eq!(walled.len(), 1);
+        assert_eq!(walled[0].name, "ada");
+        assert_eq!(
+            high_scores.entries(GameDifficulty::EASY, Topology::Wrapping)[0].name,
+            "bob"
+        );
+        assert!(high_scores
+            .entries(GameDifficulty::HARD, Topology::Walled)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_only_the_default_board_size_is_ranked() {
+        assert!(high_scores::is_ranked(&BoardConfig::default()));
+        assert!(high_scores::is_ranked(
+            &BoardConfig::default().with_topology(Topology::Wrapping)
+        ));
+        assert!(!high_scores::is_ranked(&BoardConfig::new(60, 30).unwrap()));
+        assert!(!high_scores::is_ranked(&BoardConfig::new(40, 12).unwrap()));
+    }
+
+    #[test]
+    fn test_scores_are_ranked_and_truncated() {
+        let mut high_scores = HighScores::default();
+        for score in 1..=MAX_HIGH_SCORES as u32 {
+            high_scores.insert(
+                GameDifficulty::MEDIUM,
+                Topology::Walled,
+                HighScore::new("player", score),
+            );
+        }
+
+        assert!(!high_scores.qualifies(GameDifficulty::MEDIUM, Topology::Walled, 1));
+        assert_eq!(
+            high_scores.insert(
+                GameDifficulty::MEDIUM,
+                Topology::Walled,
+                HighScore::new("late", 1)
+            ),
+            None
+        );
+
+        assert_eq!(
+            high_scores.insert(
+                GameDifficulty::MEDIUM,
+                Topology::Walled,
+                HighScore::new("best", 100)
+            ),
+            Some(0)
+        );
+
+        let entries = high_scores.entries(GameDifficulty::MEDIUM, Topology::Walled);
+        assert_eq!(entries.len(), MAX_HIGH_SCORES);
+        assert_eq!(entries[0].name, "best");
+        assert_eq!(entries.last().unwrap().score, 2);
+    }
+
+    #[test]
+    fn test_zero_never_qualifies() {
+        let high_scores = HighScores::default();
+        assert!(!high_scores.qualifies(GameDifficulty::EASY, Topology::Walled, 0));
+        assert!(high_scores.qualifies(GameDifficulty::EASY, Topology::Walled, 1));
+    }
+
+    #[test]
+    fn test_long_names_are_shortened() {
+        let high_score = HighScore::new(&"x".repeat(MAX_NAME_LENGTH * 2), 1);
+        assert_eq!(high_score.name.len(), MAX_NAME_LENGTH);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let directory = env::temp_dir().join(format!("snake-high-scores-{}", std::process::id()));
+        let path = directory.join("high_scores.json");
+        assert_eq!(HighScores::load(&path).unwrap(), HighScores::default());
+
+        let mut high_scores = HighScores::default();
+        high_scores.insert(
+            GameDifficulty::HARD,
+            Topology::Wrapping,
+            HighScore::new("ada", 12),
+        );
+        high_scores.save(&path).unwrap();
+        assert_eq!(HighScores::load(&path).unwrap(), high_scores);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+    use snake::{
+        direction::Direction,
+        input::{Controls, InputHandler, SharedKeyboard, MAX_QUEUED_TURNS},
+    };
+
+    #[test]
+    fn test_quick_turns_are_kept_in_order() {
+        let mut input_handler = InputHandler::new();
+        assert!(input_handler.push_turn(Direction::Up, Direction::Right));
+        assert!(input_handler.push_turn(Direction::Left, Direction::Right));
+
+        assert_eq!(input_handler.next_turn(), Some(Direction::Up));
+        assert_eq!(input_handler.next_turn(), Some(Direction::Left));
+        assert_eq!(input_handler.next_turn(), None);
+    }
+
+    #[test]
+    fn test_turns_are_validated_against_queued_direction() {
+        let mut input_handler = InputHandler::new();
+
+        // Reversing or repeating the current direction is ignored
+        assert!(!input_handler.push_turn(Direction::Left, Direction::Right));
+        assert!(!input_handler.push_turn(Direction::Right, Direction::Right));
+
+        // A quick Up, Left is a legal U-turn even though Left reverses Right
+        assert!(input_handler.push_turn(Direction::Up, Direction::Right));
+        assert!(!input_handler.push_turn(Direction::Down, Direction::Right));
+        assert!(input_handler.push_turn(Direction::Left, Direction::Right));
+    }
+
+    #[test]
+    fn test_queue_is_bounded() {
+        let mut input_handler = InputHandler::new();
+        let turns = [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Up,
+        ];
+
+        let queued = turns
+            .iter()
+            .filter(|&&turn| input_handler.push_turn(turn, Direction::Right))
+            .count();
+        assert_eq!(queued, MAX_QUEUED_TURNS);
+
+        for &turn in turns.iter().take(MAX_QUEUED_TURNS) {
+            assert_eq!(input_handler.next_turn(), Some(turn));
+        }
+        assert_eq!(input_handler.next_turn(), None);
+    }
+
+    #[test]
+    fn test_pause_is_not_requested_by_default() {
+        let mut input_handler = InputHandler::new();
+        assert!(!input_handler.take_pause_request());
+    }
+
+    #[test]
+    fn test_controls_map_their_own_keys() {
+        assert_eq!(Controls::Any.direction(KeyCode::Up), Some(Direction::Up));
+        assert_eq!(
+            Controls::Any.direction(KeyCode::Char('a')),
+            Some(Direction::Left)
+        );
+        assert_eq!(
+            Controls::Wasd.direction(KeyCode::Char('s')),
+            Some(Direction::Down)
+        );
+        assert_eq!(Controls::Wasd.direction(KeyCode::Down), None);
+        assert_eq!(
+            Controls::Arrows.direction(KeyCode::Right),
+            Some(Direction::Right)
+        );
+        assert_eq!(Controls::Arrows.direction(KeyCode::Char('d')), None);
+    }
+
+    #[test]
+    fn test_shared_keyboard_routes_keys_to_players() {
+        let mut keyboard = SharedKeyboard::new(&[Controls::Wasd, Controls::Arrows]);
+        let directions = [Direction::Right, Direction::Left];
+
+        assert_eq!(
+            keyboard.handle_key(KeyCode::Char('w'), &directions),
+            Some(0)
+        );
+        assert_eq!(keyboard.handle_key(KeyCode::Down, &directions), Some(1));
+        assert_eq!(keyboard.handle_key(KeyCode::Char('x'), &directions), None);
+
+        assert_eq!(keyboard.next_turn(0), Some(Direction::Up));
+        assert_eq!(keyboard.next_turn(1), Some(Direction::Down));
+        assert_eq!(keyboard.next_turn(0), None);
+        assert_eq!(keyboard.next_turn(1), None);
+    }
+
+    #[test]
+    fn test_shared_keyboard_validates_turns_per_player() {
+        let mut keyboard = SharedKeyboard::new(&[Controls::Wasd, Controls::Arrows]);
+        let directions = [Direction::Right, Direction::Left];
+
+        // Reversing is checked against each player's own snake
+        keyboard.handle_key(KeyCode::Char('a'), &directions);
+        keyboard.handle_key(KeyCode::Left, &directions);
+        keyboard.handle_key(KeyCode::Right, &directions);
+
+        assert_eq!(keyboard.next_turn(0), None);
+        assert_eq!(keyboard.next_turn(1), None);
+    }
+}
@@ -0,0 +1,261 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::BoardConfig,
+        direction::Direction,
+        net::{
+            protocol::{ClientMessage, HostMessage, PROTOCOL_VERSION},
+            Client, Host, NetError, MAX_CLIENTS,
+        },
+        simulation::DeathCause,
+        versus::{VersusOutcome, VersusSimulation},
+        versus_game::VersusSummary,
+    };
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpStream,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    fn host() -> Host {
+        Host::bind("127.0.0.1:0", "Host").unwrap()
+    }
+
+    /// Joins `host` as `name`, letting the host answer from another thread
+    /// in the meantime.
+    fn join(host: &mut Host, name: &str) -> Client {
+        let address = host.local_addr().unwrap();
+        let name = name.to_string();
+        let joining = thread::spawn(move || Client::connect(address, &name));
+        while !joining.is_finished() {
+            host.poll();
+            thread::sleep(Duration::from_millis(5));
+        }
+        joining.join().unwrap().unwrap()
+    }
+
+    /// Polls the host and `clients` until `done` holds, failing after a few
+    /// seconds.
+    fn poll_until(host: &mut Host, clients: &mut [&mut Client], done: impl Fn(&Host) -> bool) {
+        let started = Instant::now();
+        while !done(host) {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            host.poll();
+            for client in clients.iter_mut() {
+                client.poll();
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_clients_join_the_lobby() {
+        let mut host = host();
+        let mut ada = join(&mut host, "Ada");
+        let mut bob = join(&mut host, "Bob");
+
+        poll_until(&mut host, &mut [&mut ada, &mut bob], |host| {
+            host.player_count() == 3
+        });
+        let started = Instant::now();
+        while ada.lobby().len() < 3 || bob.lobby().len() < 3 {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            ada.poll();
+            bob.poll();
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        let names: Vec<&str> = bob
+            .lobby()
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect();
+        assert_eq!(names, ["Host", "Ada", "Bob"]);
+        assert!(bob.lobby()[0].host);
+        assert_eq!(ada.you(), 1);
+        assert_eq!(bob.you(), 2);
+    }
+
+    #[test]
+    fn test_game_starts_once_everyone_is_ready() {
+        let mut host = host();
+        assert!(!host.can_start());
+        let mut ada = join(&mut host, "Ada");
+        let mut bob = join(&mut host, "Bob");
+        poll_until(&mut host, &mut [], |host| host.player_count() == 3);
+        assert!(!host.can_start());
+
+        ada.set_ready(true);
+        poll_until(&mut host, &mut [], |host| host.lobby()[1].ready);
+        assert!(!host.can_start());
+
+        bob.set_ready(true);
+        poll_until(&mut host, &mut [], |host| host.can_start());
+    }
+
+    #[test]
+    fn test_clients_measure_latency() {
+        let mut host = host();
+        let mut ada = join(&mut host, "Ada");
+
+        poll_until(&mut host, &mut [&mut ada], |host| {
+            host.lobby()[1].latency_ms.is_some()
+        });
+        assert_eq!(host.latencies()[0], None);
+        assert!(host.latencies()[1].is_some());
+    }
+
+    #[test]
+    fn test_client_turns_steer_their_snake() {
+        let mut host = host();
+        let mut ada = join(&mut host, "Ada");
+        poll_until(&mut host, &mut [], |host| host.player_count() == 2);
+
+        let board = BoardConfig::default();
+        let mut simulation = VersusSimulation::new(board, 2, 0);
+        host.start(&simulation);
+
+        let started = Instant::now();
+        let (names, you) = loop {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            let start = ada.poll().into_iter().find_map(|message| match message {
+                HostMessage::Start {
+                    board: started_board,
+                    players,
+                    you,
+                } => Some((started_board, players, you)),
+                _ => None,
+            });
+            if let Some((started_board, players, you)) = start {
+                assert_eq!(started_board, board);
+                break (players, you);
+            }
+        };
+        assert_eq!(names, ["Host", "Ada"]);
+        assert_eq!(you, 1);
+
+        // The second snake starts out heading left
+        ada.send_turn(Direction::Up);
+        let mut turn = None;
+        let started = Instant::now();
+        while turn.is_none() {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            host.poll();
+            turn = host.next_turn(1);
+        }
+        assert_eq!(turn, Some(Direction::Up));
+
+        simulation.step(&[None, turn]);
+        host.send_frame(&simulation);
+
+        let started = Instant::now();
+        let frame = loop {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            let frame = ada.poll().into_iter().find_map(|message| match message {
+                HostMessage::Frame(frame) if frame.tick == 1 => Some(frame),
+                _ => None,
+            });
+            if let Some(frame) = frame {
+                break frame;
+            }
+        };
+        assert_eq!(frame.snakes.len(), 2);
+        assert_eq!(frame.snakes[1].direction, Direction::Up);
+        let head = simulation.players()[1].snake().head_position();
+        assert_eq!(frame.snakes[1].body[0], [head.0, head.1]);
+    }
+
+    #[test]
+    fn test_dropped_client_loses_their_snake() {
+        let mut host = host();
+        let ada = join(&mut host, "Ada");
+        let mut bob = join(&mut host, "Bob");
+        poll_until(&mut host, &mut [], |host| host.player_count() == 3);
+
+        let mut simulation = VersusSimulation::new(BoardConfig::default(), 3, 0);
+        host.start(&simulation);
+        drop(ada);
+
+        poll_until(&mut host, &mut [&mut bob], |host| !host.is_connected(1));
+        assert!(host.is_connected(2));
+        simulation.eliminate(1);
+        assert_eq!(simulation.step(&[]), VersusOutcome::Moved);
+        assert_eq!(
+            simulation.players()[1].death_cause(),
+            Some(DeathCause::Disconnected)
+        );
+
+        // In the lobby the dropped client is gone
+        simulation.eliminate(2);
+        simulation.step(&[]);
+        host.finish(&summary_of(&simulation));
+        host.poll();
+        assert_eq!(host.player_count(), 2);
+        assert_eq!(host.lobby()[1].name, "Bob");
+        assert!(!host.lobby()[1].ready);
+    }
+
+    fn summary_of(simulation: &VersusSimulation) -> VersusSummary {
+        VersusSummary {
+            players: Vec::new(),
+            winner: simulation.winner(),
+            ticks: simulation.ticks(),
+            time_played: Duration::ZERO,
+            seed: simulation.seed(),
+        }
+    }
+
+    #[test]
+    fn test_client_leaving_the_lobby_is_removed() {
+        let mut host = host();
+        let ada = join(&mut host, "Ada");
+        poll_until(&mut host, &mut [], |host| host.player_count() == 2);
+
+        drop(ada);
+        poll_until(&mut host, &mut [], |host| host.player_count() == 1);
+    }
+
+    #[test]
+    fn test_full_lobby_turns_clients_away() {
+        let mut host = host();
+        let _clients: Vec<Client> = (0..MAX_CLIENTS)
+            .map(|index| join(&mut host, &format!("Player {}", index)))
+            .collect();
+
+        let address = host.local_addr().unwrap();
+        let joining = thread::spawn(move || Client::connect(address, "Late"));
+        while !joining.is_finished() {
+            host.poll();
+        }
+        assert!(matches!(
+            joining.join().unwrap(),
+            Err(NetError::Rejected(_))
+        ));
+        assert_eq!(host.player_count(), 1 + MAX_CLIENTS);
+    }
+
+    #[test]
+    fn test_other_protocol_versions_are_turned_away() {
+        let mut host = host();
+        let mut stream = TcpStream::connect(host.local_addr().unwrap()).unwrap();
+        let hello = ClientMessage::Hello {
+            version: PROTOCOL_VERSION + 1,
+            name: "Future".to_string(),
+        };
+        writeln!(stream, "{}", serde_json::to_string(&hello).unwrap()).unwrap();
+
+        let reading = thread::spawn(move || {
+            let mut line = String::new();
+            BufReader::new(stream).read_line(&mut line).unwrap();
+            line
+        });
+        while !reading.is_finished() {
+            host.poll();
+        }
+
+        let reply: HostMessage = serde_json::from_str(&reading.join().unwrap()).unwrap();
+        assert!(matches!(reply, HostMessage::Rejected { .. }));
+        assert_eq!(host.player_count(), 1);
+    }
+}
@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod tests {
+    use crossterm::style::{Attribute, Color};
+    use snake::{
+        board::BoardConfig,
+        coordinate::Coordinate,
+        direction::Direction,
+        renderer::{Cell, CrosstermRenderer, FrameBuffer, Renderer, RendererBackend},
+        screen::{BoardStyle, BoardWidget, GameScreen},
+    };
+    use std::io;
+    use tui::{style, text::Spans, Terminal};
+
+    const CLEAR: &str = "\x1b[2J";
+
+    /// Draws `frame` and returns what was sent to the terminal.
+    fn draw(renderer: &mut CrosstermRenderer<Vec<u8>>, frame: &FrameBuffer) -> String {
+        renderer.draw(frame).unwrap();
+        String::from_utf8(std::mem::take(renderer.writer_mut())).unwrap()
+    }
+
+    fn frame_with_status(status: &str) -> FrameBuffer {
+        let mut frame = FrameBuffer::new(20, 5);
+        frame.print(0, 0, status, Color::Yellow, Color::Reset);
+        frame
+    }
+
+    #[test]
+    fn test_first_frame_is_drawn_in_full() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+
+        let output = draw(&mut renderer, &frame_with_status("Score: 0"));
+
+        assert!(output.starts_with("\x1b[0m\x1b[2J"));
+        assert!(output.contains("Score: 0"));
+    }
+
+    #[test]
+    fn test_unchanged_frame_sends_nothing() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+        draw(&mut renderer, &frame_with_status("Score: 0"));
+
+        assert_eq!(draw(&mut renderer, &frame_with_status("Score: 0")), "");
+    }
+
+    #[test]
+    fn test_only_changed_cells_are_sent() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+        draw(&mut renderer, &frame_with_status("Score: 9"));
+
+        let output = draw(&mut renderer, &frame_with_status("Score: 10"));
+
+        assert!(!output.contains(CLEAR));
+        assert!(!output.contains("Score"));
+        // One move to the first changed cell, then both digits in a row
+        assert!(output.starts_with("\x1b[1;8H"));
+        assert_eq!(output.matches('H').count(), 1);
+        assert!(output.contains("10"));
+    }
+
+    #[test]
+    fn test_cleared_cells_are_blanked() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+        let mut frame = FrameBuffer::new(20, 5);
+        frame.set(
+            3,
+            2,
+            Cell {
+                symbol: '@',
+                foreground: Color::Reset,
+                background: Color::Yellow,
+                ..Cell::default()
+            },
+        );
+        draw(&mut renderer, &frame);
+
+        let output = draw(&mut renderer, &FrameBuffer::new(20, 5));
+
+        assert!(output.starts_with("\x1b[3;4H"));
+        assert!(output.contains(' '));
+        assert!(!output.contains('@'));
+    }
+
+    #[test]
+    fn test_resized_or_invalidated_screen_is_redrawn() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+        draw(&mut renderer, &frame_with_status("Score: 0"));
+
+        let mut larger = FrameBuffer::new(30, 5);
+        larger.print(0, 0, "Score: 0", Color::Yellow, Color::Reset);
+        let output = draw(&mut renderer, &larger);
+        assert!(output.contains(CLEAR));
+        assert!(output.contains("Score: 0"));
+
+        renderer.invalidate();
+        assert!(draw(&mut renderer, &larger).contains(CLEAR));
+    }
+
+    #[test]
+    fn test_print_is_cut_off_at_the_edge() {
+        let mut frame = FrameBuffer::new(4, 2);
+        frame.print(2, 1, "abc", Color::Reset, Color::Reset);
+        frame.print(0, 5, "off screen", Color::Reset, Color::Reset);
+
+        assert_eq!(frame.get(2, 1).unwrap().symbol, 'a');
+        assert_eq!(frame.get(3, 1).unwrap().symbol, 'b');
+        assert_eq!(frame.get(4, 1), None);
+        assert_eq!(frame.get(0, 0).unwrap().symbol, ' ');
+    }
+
+    #[test]
+    fn test_cursor_is_hidden_and_shown_when_it_changes() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+        let mut frame = frame_with_status("Name: ");
+        assert!(draw(&mut renderer, &frame).ends_with("\x1b[?25l"));
+
+        frame.set_cursor(Some((6, 0)));
+        assert_eq!(draw(&mut renderer, &frame), "\x1b[1;7H\x1b[?25h");
+        assert_eq!(draw(&mut renderer, &frame), "");
+
+        frame.set_cursor(None);
+        assert_eq!(draw(&mut renderer, &frame), "\x1b[?25l");
+    }
+
+    #[test]
+    fn test_attributes_are_reset_between_cells() {
+        let mut renderer = CrosstermRenderer::new(Vec::new());
+        let mut frame = FrameBuffer::new(4, 1);
+        let mut bold = Cell {
+            symbol: 'B',
+            ..Cell::default()
+        };
+        bold.attributes.set(Attribute::Bold);
+        frame.set(0, 0, bold);
+        frame.set(
+            1,
+            0,
+            Cell {
+                symbol: 'n',
+                ..Cell::default()
+            },
+        );
+
+        let output = draw(&mut renderer, &frame);
+
+        let (before, after) = output.split_once('B').unwrap();
+        assert!(before.contains("\x1b[1m"));
+        let (between, _) = after.split_once('n').unwrap();
+        assert!(between.contains("\x1b[0m"));
+    }
+
+    /// Keeps every frame it is asked to draw, on a screen of a fixed size.
+    struct Recorder {
+        frames: Vec<FrameBuffer>,
+        invalidated: bool,
+    }
+
+    impl Renderer for Recorder {
+        fn size(&self) -> (u16, u16) {
+            (40, 8)
+        }
+
+        fn draw(&mut self, frame: &FrameBuffer) -> io::Result<()> {
+            self.frames.push(frame.clone());
+            Ok(())
+        }
+
+        fn invalidate(&mut self) {
+            self.invalidated = true;
+        }
+    }
+
+    #[test]
+    fn test_tui_frames_are_drawn_through_the_renderer() {
+        let recorder = Recorder {
+            frames: Vec::new(),
+            invalidated: false,
+        };
+        let mut terminal = Terminal::new(RendererBackend::new(recorder)).unwrap();
+        let board = BoardConfig::new(10, 6).unwrap();
+        let screen = |score: u32| {
+            let widget = BoardWidget::new(&board, BoardStyle::default()).snake(
+                &[Coordinate(2, 2)],
+                Direction::Right,
+                style::Color::LightYellow,
+            );
+            GameScreen::new(
+                widget,
+                "Snake",
+                vec![Spans::from(format!("Score: {}", score))],
+            )
+        };
+
+        terminal
+            .draw(|f| f.render_widget(screen(0), f.size()))
+            .unwrap();
+        terminal
+            .draw(|f| f.render_widget(screen(1), f.size()))
+            .unwrap();
+
+        // One complete frame for each draw
+        let frames = &terminal.backend_mut().renderer_mut().frames;
+        assert_eq!(frames.len(), 2);
+        let text: String = (0..40)
+            .map(|x| frames[1].get(x, 1).unwrap().symbol)
+            .collect();
+        assert!(text.contains("Score: 1"));
+        // The board keeps its place, with tui colors mapped to crossterm ones
+        let head = frames[1].get(5, 3).unwrap();
+        assert_eq!(head.background, Color::Yellow);
+        assert_eq!(frames[1].get(3, 1).unwrap().background, Color::Blue);
+        // The title of the side panel is bold
+        let title = frames[1].get(17, 0).unwrap();
+        assert_eq!(title.symbol, 'S');
+        assert!(title.attributes.has(Attribute::Bold));
+
+        terminal.clear().unwrap();
+        assert!(terminal.backend_mut().renderer_mut().invalidated);
+    }
+}
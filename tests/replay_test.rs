@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::{BoardConfig, Topology},
+        direction::Direction,
+        game::GameDifficulty,
+        replay::{self, Replay, ReplayError, ReplayPlayer, REPLAY_VERSION},
+        simulation::Simulation,
+    };
+    use std::{env, fs};
+
+    fn recorded_game() -> Replay {
+        let board = BoardConfig::new(12, 10)
+            .unwrap()
+            .with_topology(Topology::Wrapping);
+        let mut simulation = Simulation::new(board, 1234);
+        let turns = [
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+            Direction::Right,
+        ];
+
+        for tick in 0..200 {
+            let input = if tick % 7 == 0 {
+                Some(turns[tick / 7 % turns.len()])
+            } else {
+                None
+            };
+            if simulation.is_over() {
+                break;
+            }
+            simulation.step(input);
+        }
+
+        Replay::from_simulation(&simulation, GameDifficulty::HARD)
+    }
+
+    #[test]
+    fn test_replay_captures_game_settings() {
+        let replay = recorded_game();
+        assert_eq!(replay.header.version, REPLAY_VERSION);
+        assert_eq!(replay.header.seed, 1234);
+        assert_eq!(replay.header.board.width, 12);
+        assert_eq!(replay.header.board.topology, Topology::Wrapping);
+        assert_eq!(replay.header.difficulty, GameDifficulty::HARD);
+        assert_eq!(replay.inputs.len() as u64, replay.header.ticks);
+    }
+
+    #[test]
+    fn test_replay_round_trip() {
+        let replay = recorded_game();
+        let mut bytes = Vec::new();
+        replay.write_to(&mut bytes).unwrap();
+
+        let text = String::from_utf8(bytes.clone()).unwrap();
+        assert_eq!(text.lines().count(), replay.inputs.len() + 1);
+
+        let loaded = Replay::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(loaded, replay);
+    }
+
+    #[test]
+    fn test_replay_reproduces_game() {
+        let replay = recorded_game();
+        let simulation = replay.play_to_end();
+        assert_eq!(simulation.score(), replay.header.score);
+        assert_eq!(simulation.ticks(), replay.header.ticks);
+        assert_eq!(simulation.death_cause(), replay.header.death_cause);
+    }
+
+    #[test]
+    fn test_replay_rejects_other_versions() {
+        let text = "{\"version\":999}\nnull\n";
+        match Replay::read_from(text.as_bytes()) {
+            Err(ReplayError::UnsupportedVersion(999)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(matches!(
+            Replay::read_from("".as_bytes()),
+            Err(ReplayError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_replay_save_and_load() {
+        let directory = env::temp_dir().join(format!("snake-replay-test-{}", std::process::id()));
+        let replay = recorded_game();
+
+        let path = replay.save(&directory).unwrap();
+        assert_eq!(Replay::load(&path).unwrap(), replay);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_replay_save_never_overwrites() {
+        let directory = env::temp_dir().join(format!("snake-replay-unique-{}", std::process::id()));
+        let first = recorded_game();
+        let mut second = recorded_game();
+        second.header.score += 1;
+
+        let first_path = first.save(&directory).unwrap();
+        let second_path = second.save(&directory).unwrap();
+        assert_ne!(first_path, second_path);
+        assert_eq!(Replay::load(&first_path).unwrap(), first);
+        assert_eq!(Replay::load(&second_path).unwrap(), second);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_replay_player_steps_through_game() {
+        let replay = recorded_game();
+        let expected = replay.play_to_end();
+        let mut player = ReplayPlayer::new(replay.clone());
+        assert_eq!(player.position(), 0);
+
+        player.advance();
+        assert_eq!(player.position(), 1);
+        assert_eq!(player.simulation().ticks(), 1);
+
+        player.jump_to_end();
+        assert!(player.is_finished());
+        assert_eq!(player.position(), replay.inputs.len());
+        assert_eq!(player.advance(), None);
+        assert_eq!(player.simulation().score(), expected.score());
+        assert_eq!(player.simulation().snake().body(), expected.snake().body());
+    }
+
+    #[test]
+    fn test_list_replays_newest_first() {
+        let directory = env::temp_dir().join(format!("snake-replay-list-{}", std::process::id()));
+        let mut older = recorded_game();
+        older.header.recorded_at = 100;
+        let mut newer = recorded_game();
+        newer.header.recorded_at = 200;
+        older.save(&directory).unwrap();
+        newer.save(&directory).unwrap();
+        fs::write(directory.join("notes.txt"), "not a replay").unwrap();
+
+        let replays = replay::list_replays(&directory).unwrap();
+        let times: Vec<u64> = replays
+            .iter()
+            .map(|(_, header)| header.recorded_at)
+            .collect();
+        assert_eq!(times, vec![200, 100]);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
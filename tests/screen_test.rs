@@ -0,0 +1,367 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::{BoardConfig, Topology},
+        constants::HUD_WIDTH,
+        coordinate::Coordinate,
+        direction::Direction,
+        screen::{BoardStyle, BoardWidget, GameScreen, Glyphs, Overlay, RenderMode},
+    };
+    use tui::{backend::TestBackend, buffer::Buffer, style::Color, text::Spans, Terminal};
+
+    /// Draws `screen` on a terminal of the given size.
+    fn draw(screen: GameScreen, columns: u16, rows: u16) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(columns, rows)).unwrap();
+        terminal
+            .draw(|f| f.render_widget(screen, f.size()))
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// The text of row `y`.
+    fn row(buffer: &Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer.get(x, y).symbol.as_str())
+            .collect()
+    }
+
+    fn small_board() -> BoardConfig {
+        BoardConfig::new(10, 6).unwrap()
+    }
+
+    fn style(render_mode: RenderMode, glyphs: Glyphs) -> BoardStyle {
+        BoardStyle {
+            render_mode,
+            glyphs,
+        }
+    }
+
+    /// The symbols from column `start` to `end` of row `y`.
+    fn symbols(buffer: &Buffer, start: u16, end: u16, y: u16) -> String {
+        (start..end)
+            .map(|x| buffer.get(x, y).symbol.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_board_is_centered_next_to_the_side_panel() {
+        let board = small_board();
+        let widget = BoardWidget::new(&board, BoardStyle::default())
+            .snake(
+                &[Coordinate(3, 2), Coordinate(2, 2)],
+                Direction::Right,
+                Color::LightYellow,
+            )
+            .food(Coordinate(6, 4));
+
+        let buffer = draw(
+            GameScreen::new(widget, "Snake", vec![Spans::from("Score: 7")]),
+            20 + HUD_WIDTH,
+            10,
+        );
+
+        // Five columns and two rows left around the board
+        assert_eq!(buffer.get(5, 2).bg, Color::LightBlue);
+        assert_eq!(buffer.get(4, 2).bg, Color::Reset);
+        assert_eq!(buffer.get(14, 7).bg, Color::LightBlue);
+        assert_eq!(buffer.get(6, 3).bg, Color::Black);
+        assert_eq!(buffer.get(8, 4).symbol, "▶");
+        assert_eq!(buffer.get(8, 4).bg, Color::LightYellow);
+        assert_eq!(buffer.get(11, 6).symbol, "*");
+
+        assert!(row(&buffer, 0).ends_with(&format!("┌Snake{}┐", "─".repeat(17))));
+        assert!(row(&buffer, 1).contains("│Score: 7"));
+    }
+
+    #[test]
+    fn test_border_and_snakes_are_drawn_over_the_food() {
+        let board = small_board().with_topology(Topology::Wrapping);
+        let widget = BoardWidget::new(&board, BoardStyle::default())
+            .food(Coordinate(2, 2))
+            .snake(
+                &[Coordinate(2, 2), Coordinate(0, 2)],
+                Direction::Right,
+                Color::LightGreen,
+            )
+            .snake(&[Coordinate(2, 2)], Direction::Left, Color::LightMagenta);
+
+        let buffer = draw(
+            GameScreen::new(widget, "Versus", Vec::new()),
+            10 + HUD_WIDTH,
+            6,
+        );
+
+        assert_eq!(buffer.get(2, 2).bg, Color::LightGreen);
+        assert_eq!(buffer.get(0, 2).bg, Color::DarkGray);
+        assert_eq!(buffer.get(0, 2).symbol, " ");
+    }
+
+    #[test]
+    fn test_board_larger_than_the_area_is_cut_off() {
+        let board = BoardConfig::new(40, 20).unwrap();
+
+        let buffer = draw(
+            GameScreen::new(
+                BoardWidget::new(&board, BoardStyle::default()),
+                "Snake",
+                Vec::new(),
+            ),
+            10 + HUD_WIDTH,
+            5,
+        );
+
+        assert_eq!(buffer.get(9, 0).bg, Color::LightBlue);
+        assert_eq!(buffer.get(9, 1).bg, Color::Black);
+        // The side panel keeps its place
+        assert_eq!(buffer.get(10, 0).symbol, "┌");
+    }
+
+    #[test]
+    fn test_overlay_menu_highlights_the_selection() {
+        let items = vec!["Resume".to_string(), "Quit to Menu".to_string()];
+        let screen = GameScreen::new(
+            BoardWidget::new(&small_board(), BoardStyle::default()),
+            "Snake",
+            Vec::new(),
+        )
+        .overlay(Overlay::menu("Paused", items, 1));
+
+        let buffer = draw(screen, 30 + HUD_WIDTH, 10);
+
+        let rows: Vec<String> = (0..10).map(|y| row(&buffer, y)).collect();
+        let title = rows.iter().position(|row| row.contains("Paused")).unwrap();
+        assert!(rows[title + 2].contains("   Resume"));
+        let selected = rows.iter().position(|row| row.contains("> Quit to Menu"));
+        assert_eq!(selected, Some(title + 3));
+        // The 20x6 box starts at column 5
+        assert_eq!(buffer.get(7, title as u16 + 3).symbol, ">");
+        assert_eq!(buffer.get(7, title as u16 + 3).bg, Color::Yellow);
+        assert_eq!(buffer.get(7, title as u16 + 2).bg, Color::Blue);
+    }
+
+    #[test]
+    fn test_overlay_message_is_centered_on_the_board() {
+        let screen = GameScreen::new(
+            BoardWidget::new(&small_board(), BoardStyle::default()),
+            "Snake",
+            Vec::new(),
+        )
+        .overlay(Overlay::message("Game Over", vec!["Score: 3".to_string()]));
+
+        let buffer = draw(screen, 30 + HUD_WIDTH, 11);
+
+        // 15 columns wide and 5 rows high, in the middle of the 30x11 board area
+        assert_eq!(buffer.get(7, 3).symbol, "┌");
+        assert_eq!(buffer.get(21, 7).symbol, "┘");
+        assert!(row(&buffer, 3).contains("Game Over"));
+        assert!(row(&buffer, 5).contains("│ Score: 3"));
+    }
+
+    #[test]
+    fn test_half_block_mode_packs_two_rows_per_character() {
+        // Seven rows take four characters, the last one only half used
+        let board = BoardConfig::new(10, 7).unwrap();
+        let widget = BoardWidget::new(&board, style(RenderMode::HalfBlock, Glyphs::Unicode))
+            .snake(
+                &[Coordinate(3, 2), Coordinate(3, 3)],
+                Direction::Up,
+                Color::LightYellow,
+            )
+            .food(Coordinate(6, 1));
+
+        let buffer = draw(
+            GameScreen::new(widget, "Snake", Vec::new()),
+            10 + HUD_WIDTH,
+            4,
+        );
+
+        // Top wall over the food
+        assert_eq!(buffer.get(6, 0).symbol, "▀");
+        assert_eq!(buffer.get(6, 0).fg, Color::LightBlue);
+        assert_eq!(buffer.get(6, 0).bg, Color::LightRed);
+        // Both halves of the snake in one character
+        assert_eq!(buffer.get(3, 1).symbol, " ");
+        assert_eq!(buffer.get(3, 1).bg, Color::LightYellow);
+        // Empty cells over empty cells
+        assert_eq!(buffer.get(5, 1).bg, Color::Black);
+        // The bottom wall alone in the top half of the last row
+        assert_eq!(buffer.get(5, 3).symbol, "▀");
+        assert_eq!(buffer.get(5, 3).fg, Color::LightBlue);
+        assert_eq!(buffer.get(5, 3).bg, Color::Reset);
+    }
+
+    #[test]
+    fn test_double_width_mode_draws_two_characters_per_cell() {
+        let widget = BoardWidget::new(
+            &small_board(),
+            style(RenderMode::DoubleWidth, Glyphs::Unicode),
+        )
+        .snake(&[Coordinate(3, 2)], Direction::Right, Color::LightYellow)
+        .food(Coordinate(6, 4));
+
+        let buffer = draw(
+            GameScreen::new(widget, "Snake", Vec::new()),
+            24 + HUD_WIDTH,
+            6,
+        );
+
+        // Two columns left on each side of the 20 columns wide board
+        assert_eq!(buffer.get(1, 0).bg, Color::Reset);
+        assert_eq!(buffer.get(2, 0).bg, Color::LightBlue);
+        assert_eq!(buffer.get(21, 0).bg, Color::LightBlue);
+        assert_eq!(buffer.get(22, 0).bg, Color::Reset);
+        assert_eq!(buffer.get(8, 2).symbol, "▶");
+        assert_eq!(buffer.get(9, 2).symbol, " ");
+        assert_eq!(buffer.get(9, 2).bg, Color::LightYellow);
+        assert_eq!(buffer.get(14, 4).symbol, "*");
+        assert_eq!(buffer.get(15, 4).bg, Color::LightRed);
+    }
+
+    #[test]
+    fn test_validate_for_terminal() {
+        let board = BoardConfig::default();
+        let classic = RenderMode::Classic;
+        assert!(classic.validate_for_terminal(&board, 80, 24).is_ok());
+        assert!(classic.validate_for_terminal(&board, 39, 24).is_err());
+        // Columns are reserved for the side panel
+        assert!(classic
+            .validate_for_terminal(&board, 40 + HUD_WIDTH, 20)
+            .is_ok());
+        assert!(classic
+            .validate_for_terminal(&board, 39 + HUD_WIDTH, 20)
+            .is_err());
+        assert!(classic
+            .validate_for_terminal(&board, 40 + HUD_WIDTH, 19)
+            .is_err());
+
+        let filled = classic.fill_terminal(80, 24).unwrap();
+        assert_eq!(filled, BoardConfig::new(80 - HUD_WIDTH as i32, 24).unwrap());
+        assert!(classic.validate_for_terminal(&filled, 80, 24).is_ok());
+    }
+
+    #[test]
+    fn test_validate_for_terminal_in_other_render_modes() {
+        let board = BoardConfig::new(41, 21).unwrap();
+
+        // Two rows of cells per terminal row, rounded up
+        let half_block = RenderMode::HalfBlock;
+        assert!(half_block
+            .validate_for_terminal(&board, 41 + HUD_WIDTH, 11)
+            .is_ok());
+        assert!(half_block
+            .validate_for_terminal(&board, 41 + HUD_WIDTH, 10)
+            .is_err());
+        let filled = half_block.fill_terminal(80, 24).unwrap();
+        assert_eq!(filled, BoardConfig::new(80 - HUD_WIDTH as i32, 48).unwrap());
+        assert!(half_block.validate_for_terminal(&filled, 80, 24).is_ok());
+
+        // Two columns per cell
+        let double_width = RenderMode::DoubleWidth;
+        assert!(double_width
+            .validate_for_terminal(&board, 82 + HUD_WIDTH, 21)
+            .is_ok());
+        assert!(double_width
+            .validate_for_terminal(&board, 81 + HUD_WIDTH, 21)
+            .is_err());
+        let filled = double_width.fill_terminal(81 + HUD_WIDTH, 24).unwrap();
+        assert_eq!(filled, BoardConfig::new(40, 24).unwrap());
+    }
+
+    #[test]
+    fn test_render_modes_parse_from_their_names() {
+        assert_eq!("classic".parse(), Ok(RenderMode::Classic));
+        assert_eq!("half-block".parse(), Ok(RenderMode::HalfBlock));
+        assert_eq!("Double-Width".parse(), Ok(RenderMode::DoubleWidth));
+        assert!("braille".parse::<RenderMode>().is_err());
+        for mode in RenderMode::ALL {
+            assert_eq!(mode.name().parse(), Ok(mode));
+        }
+        assert_eq!("ascii".parse(), Ok(Glyphs::Ascii));
+        assert_eq!("Unicode".parse(), Ok(Glyphs::Unicode));
+    }
+
+    /// Draws a snake on the small board, from head to tail: up from the
+    /// third column, right along the second row, down a row and right again.
+    fn coiled_snake(glyphs: Glyphs) -> Buffer {
+        let body = [
+            Coordinate(3, 1),
+            Coordinate(3, 2),
+            Coordinate(4, 2),
+            Coordinate(5, 2),
+            Coordinate(5, 3),
+            Coordinate(6, 3),
+        ];
+        let widget = BoardWidget::new(&small_board(), style(RenderMode::Classic, glyphs)).snake(
+            &body,
+            Direction::Up,
+            Color::LightYellow,
+        );
+        draw(
+            GameScreen::new(widget, "Snake", Vec::new()),
+            10 + HUD_WIDTH,
+            6,
+        )
+    }
+
+    #[test]
+    fn test_snake_glyphs_show_the_head_turns_and_tail() {
+        let buffer = coiled_snake(Glyphs::Unicode);
+
+        assert_eq!(buffer.get(3, 1).symbol, "▲");
+        assert_eq!(symbols(&buffer, 3, 6, 2), "└─┐");
+        assert_eq!(symbols(&buffer, 5, 7, 3), "└╴");
+        // Drawn in black on the snake's color
+        assert_eq!(buffer.get(4, 2).fg, Color::Black);
+        assert_eq!(buffer.get(4, 2).bg, Color::LightYellow);
+    }
+
+    #[test]
+    fn test_ascii_snake_glyphs() {
+        let buffer = coiled_snake(Glyphs::Ascii);
+
+        assert_eq!(buffer.get(3, 1).symbol, "^");
+        assert_eq!(symbols(&buffer, 3, 6, 2), "+-+");
+        assert_eq!(symbols(&buffer, 5, 7, 3), "+.");
+    }
+
+    #[test]
+    fn test_snake_glyphs_join_across_wrapping_edges() {
+        let board = small_board().with_topology(Topology::Wrapping);
+        let widget = BoardWidget::new(&board, BoardStyle::default()).snake(
+            &[Coordinate(1, 2), Coordinate(8, 2)],
+            Direction::Left,
+            Color::LightYellow,
+        );
+
+        let buffer = draw(
+            GameScreen::new(widget, "Snake", Vec::new()),
+            10 + HUD_WIDTH,
+            6,
+        );
+
+        assert_eq!(buffer.get(1, 2).symbol, "◀");
+        // The tail is joined to the head on the other side of the board
+        assert_eq!(buffer.get(8, 2).symbol, "╶");
+    }
+
+    #[test]
+    fn test_double_width_snakes_continue_into_the_second_column() {
+        let widget = BoardWidget::new(
+            &small_board(),
+            style(RenderMode::DoubleWidth, Glyphs::Unicode),
+        )
+        .snake(
+            &[Coordinate(4, 2), Coordinate(3, 2), Coordinate(2, 2)],
+            Direction::Right,
+            Color::LightYellow,
+        );
+
+        let buffer = draw(
+            GameScreen::new(widget, "Snake", Vec::new()),
+            20 + HUD_WIDTH,
+            6,
+        );
+
+        assert_eq!(symbols(&buffer, 4, 10, 2), "╶───▶ ");
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use snake::{
+        board::BoardConfig,
+        constants::{BOARD_HEIGHT, BOARD_WIDTH},
+        coordinate::Coordinate,
+        direction::Direction,
+        food::Food,
+        simulation::{DeathCause, Simulation, StepOutcome},
+        snake::Snake,
+    };
+
+    #[test]
+    fn test_simulation_initialization() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        assert_eq!(simulation.score(), 0);
+        assert_eq!(simulation.ticks(), 0);
+        assert!(!simulation.is_over());
+        assert!(!simulation
+            .snake()
+            .body()
+            .contains(&simulation.food().position));
+    }
+
+    #[test]
+    fn test_step_moves_snake() {
+        let mut simulation = Simulation::new(BoardConfig::default(), 0);
+        simulation.step(None);
+        assert_eq!(
+            simulation.snake().head_position(),
+            Coordinate(BOARD_WIDTH / 2 + 1, BOARD_HEIGHT / 2)
+        );
+
+        simulation.step(Some(Direction::Down));
+        assert_eq!(
+            simulation.snake().head_position(),
+            Coordinate(BOARD_WIDTH / 2 + 1, BOARD_HEIGHT / 2 + 1)
+        );
+        assert_eq!(simulation.ticks(), 2);
+    }
+
+    #[test]
+    fn test_step_ignores_reverse_input() {
+        let mut simulation = Simulation::new(BoardConfig::default(), 0);
+        simulation.step(Some(Direction::Left));
+        assert_eq!(simulation.snake().direction, Direction::Right);
+    }
+
+    #[test]
+    fn test_step_into_wall_ends_game() {
+        let mut simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut outcome = StepOutcome::Moved;
+        for _ in 0..BOARD_HEIGHT {
+            outcome = simulation.step(Some(Direction::Up));
+            if simulation.is_over() {
+                break;
+            }
+        }
+
+        assert_eq!(outcome, StepOutcome::Died(DeathCause::Wall));
+        assert_eq!(simulation.death_cause(), Some(DeathCause::Wall));
+
+        // A finished game no longer advances
+        let ticks = simulation.ticks();
+        assert_eq!(simulation.step(None), StepOutcome::Died(DeathCause::Wall));
+        assert_eq!(simulation.ticks(), ticks);
+    }
+
+    #[test]
+    fn test_death_causes_read_as_text() {
+        assert_eq!(DeathCause::Wall.to_string(), "Hit the wall");
+        assert_eq!(DeathCause::SelfCollision.to_string(), "Ran into itself");
+        assert_eq!(DeathCause::HeadOn.to_string(), "Crashed head-on");
+    }
+
+    #[test]
+    fn test_same_seed_spawns_same_food() {
+        let first = Simulation::new(BoardConfig::default(), 42);
+        let second = Simulation::new(BoardConfig::default(), 42);
+        assert_eq!(first.seed(), 42);
+        assert_eq!(first.food().position, second.food().position);
+    }
+
+    #[test]
+    fn test_seed_spawns_the_same_food_on_every_platform() {
+        let board = BoardConfig::default();
+        let snake = Snake::new(board);
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        let positions: Vec<Coordinate> = (0..3)
+            .map(|_| Food::new(&board, &snake, &mut rng).unwrap().position)
+            .collect();
+
+        // Pinned so that a seed shared between machines replays the same game
+        assert_eq!(
+            positions,
+            vec![Coordinate(5, 18), Coordinate(12, 12), Coordinate(8, 6)]
+        );
+        assert_eq!(Simulation::new(board, 42).food().position, positions[0]);
+    }
+
+    #[test]
+    fn test_same_seed_and_inputs_replay_identically() {
+        let inputs = [
+            None,
+            Some(Direction::Down),
+            None,
+            Some(Direction::Left),
+            None,
+            Some(Direction::Up),
+        ];
+        let mut first = Simulation::new(BoardConfig::default(), 7);
+        let mut second = Simulation::new(BoardConfig::default(), 7);
+
+        for _ in 0..5 {
+            for &input in &inputs {
+                assert_eq!(first.step(input), second.step(input));
+                assert_eq!(first.snake().body(), second.snake().body());
+                assert_eq!(first.food().position, second.food().position);
+            }
+        }
+        assert_eq!(first.score(), second.score());
+    }
+
+    #[test]
+    fn test_food_is_placed_on_the_last_free_cell() {
+        let board = BoardConfig::new(5, 5).unwrap();
+        let mut snake = Snake::new(board);
+        snake.body = (1..4)
+            .flat_map(|y| (1..4).map(move |x| Coordinate(x, y)))
+            .filter(|&cell| cell != Coordinate(3, 3))
+            .collect();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..10 {
+            let food = Food::new(&board, &snake, &mut rng).unwrap();
+            assert_eq!(food.position, Coordinate(3, 3));
+        }
+    }
+
+    #[test]
+    fn test_no_food_on_full_board() {
+        let board = BoardConfig::new(5, 5).unwrap();
+        let mut snake = Snake::new(board);
+        snake.body = (1..4)
+            .flat_map(|y| (1..4).map(move |x| Coordinate(x, y)))
+            .collect();
+
+        assert!(Food::new(&board, &snake, &mut ChaCha8Rng::seed_from_u64(0)).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use snake::{
+        board::BoardConfig,
         constants::{BOARD_HEIGHT, BOARD_WIDTH},
         coordinate::Coordinate,
         direction::Direction,
@@ -9,7 +10,7 @@ mod tests {
 
     #[test]
     fn test_snake_initialization() {
-        let snake = Snake::new();
+        let snake = Snake::new(BoardConfig::default());
         assert_eq!(snake.body.len(), 1);
         assert_eq!(snake.body[0], Coordinate(BOARD_WIDTH / 2, BOARD_HEIGHT / 2));
         assert_eq!(snake.direction, Direction::Right);
@@ -18,7 +19,7 @@ mod tests {
 
     #[test]
     fn test_change_direction() {
-        let mut snake = Snake::new();
+        let mut snake = Snake::new(BoardConfig::default());
         snake.change_direction(Direction::Up);
         assert_eq!(snake.direction, Direction::Up);
 
@@ -35,7 +36,7 @@ mod tests {
 
     #[test]
     fn test_move_forward() {
-        let mut snake = Snake::new();
+        let mut snake = Snake::new(BoardConfig::default());
         snake.move_forward();
         assert_eq!(snake.body.len(), 1);
         assert_eq!(
@@ -53,7 +54,7 @@ mod tests {
 
     #[test]
     fn test_grow() {
-        let mut snake = Snake::new();
+        let mut snake = Snake::new(BoardConfig::default());
         snake.grow();
         snake.move_forward();
         assert_eq!(snake.body.len(), 2);
@@ -66,7 +67,7 @@ mod tests {
 
     #[test]
     fn test_collides_with_wall() {
-        let mut snake = Snake::new();
+        let mut snake = Snake::new(BoardConfig::default());
         for _ in 0..BOARD_WIDTH {
             snake.move_forward();
         }
@@ -75,7 +76,7 @@ mod tests {
 
     #[test]
     fn test_body() {
-        let snake = Snake::new();
+        let snake = Snake::new(BoardConfig::default());
         let body = snake.body();
         assert_eq!(body.len(), 1);
         assert_eq!(body[0], Coordinate(BOARD_WIDTH / 2, BOARD_HEIGHT / 2));
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::BoardConfig,
+        net::{
+            protocol::{SpectatorFrame, SpectatorMessage, SPECTATOR_PROTOCOL_VERSION},
+            Broadcaster, Spectator,
+        },
+        simulation::Simulation,
+        versus::VersusSimulation,
+    };
+    use std::{
+        io::Write,
+        net::{TcpListener, TcpStream},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    /// Waits until the broadcaster accepted `count` spectators.
+    fn wait_for_spectators(broadcaster: &Broadcaster, count: usize) {
+        let started = Instant::now();
+        while broadcaster.spectator_count() != count {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// Waits for the next message published to `spectator`.
+    fn next_message(spectator: &mut Spectator) -> SpectatorMessage {
+        let started = Instant::now();
+        loop {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            if let Some(message) = spectator.poll().into_iter().next() {
+                return message;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    fn frame(tick: u64) -> SpectatorMessage {
+        let mut simulation = Simulation::new(BoardConfig::default(), 0);
+        for _ in 0..tick {
+            simulation.step(None);
+        }
+        SpectatorMessage::Frame(SpectatorFrame::from_simulation(&simulation, "Ada", false))
+    }
+
+    #[test]
+    fn test_every_spectator_receives_published_frames() {
+        let broadcaster = Broadcaster::bind("127.0.0.1:0").unwrap();
+        let mut first = Spectator::connect(broadcaster.local_addr()).unwrap();
+        let mut second = Spectator::connect(broadcaster.local_addr()).unwrap();
+        wait_for_spectators(&broadcaster, 2);
+
+        broadcaster.publish(&frame(1));
+
+        assert_eq!(next_message(&mut first), frame(1));
+        assert_eq!(next_message(&mut second), frame(1));
+    }
+
+    #[test]
+    fn test_spectators_attach_and_detach_at_any_time() {
+        let broadcaster = Broadcaster::bind("127.0.0.1:0").unwrap();
+        let mut early = Spectator::connect(broadcaster.local_addr()).unwrap();
+        wait_for_spectators(&broadcaster, 1);
+        broadcaster.publish(&frame(1));
+        assert_eq!(next_message(&mut early), frame(1));
+
+        // A late spectator picks up from the next frame on
+        let mut late = Spectator::connect(broadcaster.local_addr()).unwrap();
+        wait_for_spectators(&broadcaster, 2);
+        broadcaster.publish(&frame(2));
+        assert_eq!(next_message(&mut late), frame(2));
+        assert_eq!(next_message(&mut early), frame(2));
+
+        // The game goes on for the others when a spectator leaves
+        drop(early);
+        let started = Instant::now();
+        while broadcaster.spectator_count() > 1 {
+            assert!(started.elapsed() < Duration::from_secs(5), "timed out");
+            broadcaster.publish(&frame(3));
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(next_message(&mut late), frame(3));
+    }
+
+    #[test]
+    fn test_publishing_without_spectators_does_nothing() {
+        let broadcaster = Broadcaster::bind("127.0.0.1:0").unwrap();
+        broadcaster.publish(&frame(1));
+        assert_eq!(broadcaster.spectator_count(), 0);
+    }
+
+    #[test]
+    fn test_stalled_spectator_never_blocks_publishing() {
+        let broadcaster = Broadcaster::bind("127.0.0.1:0").unwrap();
+        // Connected, but never reads
+        let _stalled = TcpStream::connect(broadcaster.local_addr()).unwrap();
+        wait_for_spectators(&broadcaster, 1);
+
+        let message = frame(1);
+        let started = Instant::now();
+        while broadcaster.spectator_count() > 0 {
+            assert!(started.elapsed() < Duration::from_secs(10), "timed out");
+            let published = Instant::now();
+            broadcaster.publish(&message);
+            assert!(published.elapsed() < Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn test_spectator_turns_away_other_versions() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let hello = SpectatorMessage::Hello {
+                version: SPECTATOR_PROTOCOL_VERSION + 1,
+            };
+            writeln!(stream, "{}", serde_json::to_string(&hello).unwrap()).unwrap();
+            thread::sleep(Duration::from_secs(1));
+        });
+
+        let error = Spectator::connect(address).unwrap_err();
+        assert!(error.to_string().contains("version"));
+    }
+
+    #[test]
+    fn test_frames_without_direction_or_pause_state_are_rejected() {
+        let json = serde_json::to_string(&frame(1)).unwrap();
+        assert!(serde_json::from_str::<SpectatorMessage>(&json).is_ok());
+
+        let without_direction = json.replace("\"direction\":\"Right\",", "");
+        assert_ne!(without_direction, json);
+        assert!(serde_json::from_str::<SpectatorMessage>(&without_direction).is_err());
+        let without_paused = json.replace(",\"paused\":false", "");
+        assert_ne!(without_paused, json);
+        assert!(serde_json::from_str::<SpectatorMessage>(&without_paused).is_err());
+    }
+
+    #[test]
+    fn test_frames_name_every_player() {
+        let board = BoardConfig::default();
+        let simulation = VersusSimulation::new(board, 2, 0);
+        let names = ["Ada".to_string(), "Bob".to_string()];
+
+        let frame = SpectatorFrame::from_versus(&simulation, &names, true);
+
+        assert_eq!(frame.board, board);
+        assert!(frame.paused);
+        assert_eq!(frame.snakes.len(), 2);
+        assert_eq!(frame.snakes[1].name, "Bob");
+        let head = simulation.players()[1].snake().head_position();
+        assert_eq!(frame.snakes[1].body, [[head.0, head.1]]);
+        assert!(frame.snakes.iter().all(|snake| snake.alive));
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::{BoardConfig, Topology},
+        coordinate::Coordinate,
+        direction::Direction,
+        game::GameDifficulty,
+        simulation::{DeathCause, Simulation, StepOutcome},
+        snake::Snake,
+        strategy::{
+            ClumsyStrategy, GameView, GreedyStrategy, HamiltonianStrategy, PathfindingStrategy,
+            Strategy, StrategyRegistry,
+        },
+        versus_game::computer_opponent,
+    };
+
+    /// Turns up on every tick, whatever the game looks like.
+    struct AlwaysUp;
+
+    impl Strategy for AlwaysUp {
+        fn name(&self) -> &str {
+            "Always Up"
+        }
+
+        fn next_direction(&mut self, _view: &GameView) -> Direction {
+            Direction::Up
+        }
+    }
+
+    /// Lets `strategy` play until it dies or reaches `target_score`.
+    fn play_until(
+        strategy: &mut dyn Strategy,
+        board: BoardConfig,
+        seed: u64,
+        target_score: u32,
+    ) -> Result<u32, DeathCause> {
+        let mut simulation = Simulation::new(board, seed);
+        while simulation.score() < target_score {
+            let direction = strategy.next_direction(&simulation.view());
+            if let StepOutcome::Died(cause) = simulation.step(Some(direction)) {
+                return Err(cause);
+            }
+        }
+        Ok(simulation.score())
+    }
+
+    #[test]
+    fn test_builtin_registry_contains_greedy() {
+        let registry = StrategyRegistry::with_builtin();
+        assert!(registry.names().any(|name| name == "Greedy"));
+        assert_eq!(registry.create("Greedy").unwrap().name(), "Greedy");
+        assert!(registry.create("Missing").is_none());
+    }
+
+    #[test]
+    fn test_register_custom_strategy() {
+        let mut registry = StrategyRegistry::new();
+        registry.register("Always Up", || Box::new(AlwaysUp));
+
+        assert_eq!(registry.names().collect::<Vec<_>>(), vec!["Always Up"]);
+
+        let mut strategy = registry.create("Always Up").unwrap();
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        assert_eq!(strategy.next_direction(&simulation.view()), Direction::Up);
+    }
+
+    #[test]
+    fn test_register_replaces_strategy_with_same_name() {
+        let mut registry = StrategyRegistry::with_builtin();
+        registry.register("Greedy", || Box::new(AlwaysUp));
+
+        assert_eq!(registry.names().filter(|&name| name == "Greedy").count(), 1);
+        assert_eq!(registry.create("Greedy").unwrap().name(), "Always Up");
+    }
+
+    #[test]
+    fn test_greedy_strategy_reaches_food() {
+        let mut simulation = Simulation::new(BoardConfig::default(), 7);
+        let mut strategy = GreedyStrategy;
+
+        let mut ate = false;
+        for _ in 0..200 {
+            let direction = strategy.next_direction(&simulation.view());
+            match simulation.step(Some(direction)) {
+                StepOutcome::Ate | StepOutcome::Won => {
+                    ate = true;
+                    break;
+                }
+                StepOutcome::Died(cause) => panic!("greedy strategy died: {:?}", cause),
+                StepOutcome::Moved => {}
+            }
+        }
+
+        assert!(ate);
+    }
+
+    #[test]
+    fn test_builtin_registry_contains_pathfinding() {
+        let registry = StrategyRegistry::with_builtin();
+        assert_eq!(
+            registry.create("Pathfinding").unwrap().name(),
+            "Pathfinding"
+        );
+    }
+
+    #[test]
+    fn test_pathfinding_strategy_survives_where_greedy_traps_itself() {
+        let board = BoardConfig::new(10, 8).unwrap();
+
+        assert!(play_until(&mut GreedyStrategy, board, 0, 40).is_err());
+        assert_eq!(play_until(&mut PathfindingStrategy, board, 0, 40), Ok(40));
+    }
+
+    #[test]
+    fn test_pathfinding_strategy_on_wrapping_board() {
+        let board = BoardConfig::new(20, 12)
+            .unwrap()
+            .with_topology(Topology::Wrapping);
+
+        for seed in 0..3 {
+            assert_eq!(
+                play_until(&mut PathfindingStrategy, board, seed, 60),
+                Ok(60)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hamiltonian_strategy_fills_default_board() {
+        let board = BoardConfig::default();
+        let mut simulation = Simulation::new(board, 0);
+        let mut strategy = HamiltonianStrategy::default();
+
+        let outcome = loop {
+            match simulation.step(Some(strategy.next_direction(&simulation.view()))) {
+                StepOutcome::Moved | StepOutcome::Ate => {}
+                outcome => break outcome,
+            }
+        };
+
+        assert_eq!(outcome, StepOutcome::Won);
+        assert!(simulation.is_won());
+        assert!(simulation.is_over());
+        assert_eq!(simulation.death_cause(), None);
+        assert_eq!(simulation.snake().body().len(), board.playable_area());
+        assert_eq!(simulation.step(None), StepOutcome::Won);
+    }
+
+    #[test]
+    fn test_hamiltonian_strategy_wins_on_wrapping_board() {
+        let board = BoardConfig::new(20, 12)
+            .unwrap()
+            .with_topology(Topology::Wrapping);
+
+        for seed in 0..3 {
+            let target = board.playable_area() as u32 - 1;
+            assert_eq!(
+                play_until(&mut HamiltonianStrategy::default(), board, seed, target),
+                Ok(target)
+            );
+        }
+    }
+
+    /// A view where the food lies straight ahead of a snake at (5, 10)
+    /// heading right, with `opponent` on the board.
+    fn view_with_opponent<'a>(
+        board: &'a BoardConfig,
+        snake: &'a Snake,
+        opponent: &'a Snake,
+    ) -> GameView<'a> {
+        GameView {
+            board,
+            snake,
+            food: Coordinate(10, 10),
+            score: 0,
+            opponents: vec![opponent],
+        }
+    }
+
+    #[test]
+    fn test_strategies_avoid_opponent_body() {
+        let board = BoardConfig::default();
+        let snake = Snake::spawn(board, Coordinate(5, 10), Direction::Right);
+        let mut opponent = Snake::spawn(board, Coordinate(6, 8), Direction::Up);
+        opponent.body = (8..=12).map(|y| Coordinate(6, y)).collect();
+        let view = view_with_opponent(&board, &snake, &opponent);
+
+        assert_ne!(GreedyStrategy.next_direction(&view), Direction::Right);
+        assert_ne!(PathfindingStrategy.next_direction(&view), Direction::Right);
+    }
+
+    #[test]
+    fn test_pathfinding_strategy_avoids_head_on_collision() {
+        let board = BoardConfig::default();
+        let snake = Snake::spawn(board, Coordinate(5, 10), Direction::Right);
+        let opponent = Snake::spawn(board, Coordinate(7, 10), Direction::Left);
+        let view = view_with_opponent(&board, &snake, &opponent);
+
+        // Greedy only looks at where the opponent is, not where it goes
+        assert_eq!(GreedyStrategy.next_direction(&view), Direction::Right);
+        assert_ne!(PathfindingStrategy.next_direction(&view), Direction::Right);
+    }
+
+    #[test]
+    fn test_clumsy_strategy_lapses() {
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+
+        let mut never = ClumsyStrategy::new(Box::new(AlwaysUp), 0.0, 0);
+        let mut always = ClumsyStrategy::new(Box::new(AlwaysUp), 1.0, 0);
+        assert_eq!(never.name(), "Always Up");
+        for _ in 0..20 {
+            assert_eq!(never.next_direction(&simulation.view()), Direction::Up);
+            assert_eq!(always.next_direction(&simulation.view()), Direction::Right);
+        }
+
+        let mut sometimes = ClumsyStrategy::new(Box::new(AlwaysUp), 0.5, 0);
+        let directions: Vec<_> = (0..100)
+            .map(|_| sometimes.next_direction(&simulation.view()))
+            .collect();
+        assert!(directions.contains(&Direction::Up));
+        assert!(directions.contains(&Direction::Right));
+    }
+
+    #[test]
+    fn test_computer_opponent_gets_stronger_with_difficulty() {
+        assert_eq!(computer_opponent(GameDifficulty::EASY, 0).name(), "Greedy");
+        assert_eq!(
+            computer_opponent(GameDifficulty::MEDIUM, 0).name(),
+            "Pathfinding"
+        );
+        assert_eq!(
+            computer_opponent(GameDifficulty::HARD, 0).name(),
+            "Pathfinding"
+        );
+
+        // Without lapses the hard opponent plays exactly like pathfinding
+        let board = BoardConfig::new(10, 8).unwrap();
+        assert_eq!(
+            play_until(
+                computer_opponent(GameDifficulty::HARD, 0).as_mut(),
+                board,
+                0,
+                40
+            ),
+            Ok(40)
+        );
+    }
+}
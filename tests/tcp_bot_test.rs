@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::BoardConfig,
+        direction::Direction,
+        game::GameDifficulty,
+        simulation::Simulation,
+        strategy::{
+            protocol::{BotMode, Handshake, StateFrame, PROTOCOL_VERSION},
+            BotServer, Strategy,
+        },
+    };
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpStream,
+        sync::mpsc,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn handshake(mode: BotMode) -> Handshake {
+        Handshake::new(mode, BoardConfig::default(), GameDifficulty::MEDIUM)
+    }
+
+    /// Connects a loopback client to `server` that answers every state frame
+    /// with `reply`, and reports every line it received.
+    fn connect_bot(server: &BotServer, reply: &'static str) -> mpsc::Receiver<String> {
+        let address = server.local_addr().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            let reader = BufReader::new(stream.try_clone().unwrap());
+            for line in reader.lines() {
+                let Ok(line) = line else { break };
+                let is_frame = line.contains("\"tick\"");
+                if sender.send(line).is_err() {
+                    break;
+                }
+                if is_frame && writeln!(stream, "{}", reply).is_err() {
+                    break;
+                }
+            }
+        });
+
+        receiver
+    }
+
+    #[test]
+    fn test_parse_bot_mode() {
+        assert_eq!("lockstep".parse(), Ok(BotMode::Lockstep));
+        assert_eq!("RealTime".parse(), Ok(BotMode::RealTime));
+        assert_eq!("real-time".parse(), Ok(BotMode::RealTime));
+        assert!("sometimes".parse::<BotMode>().is_err());
+    }
+
+    #[test]
+    fn test_lockstep_bot_receives_handshake_and_steers() {
+        let server = BotServer::bind("127.0.0.1:0").unwrap();
+        let received = connect_bot(&server, "left");
+        let mut bot = server
+            .accept(&handshake(BotMode::Lockstep), TIMEOUT)
+            .unwrap();
+
+        // The handshake comes before the first tick
+        let received_handshake: Handshake =
+            serde_json::from_str(&received.recv_timeout(TIMEOUT).unwrap()).unwrap();
+        assert_eq!(
+            received_handshake,
+            Handshake {
+                version: PROTOCOL_VERSION,
+                mode: BotMode::Lockstep,
+                board: BoardConfig::default(),
+                difficulty: GameDifficulty::MEDIUM,
+                tick_ms: 100,
+            }
+        );
+
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Left);
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Left);
+        assert!(bot.error().is_none());
+
+        for tick in 1..=2 {
+            let frame: StateFrame = serde_json::from_str(&received.recv().unwrap()).unwrap();
+            assert_eq!(frame, StateFrame::new(&simulation.view(), tick));
+        }
+    }
+
+    #[test]
+    fn test_realtime_bot_plays_last_move() {
+        let server = BotServer::bind("127.0.0.1:0").unwrap();
+        let _received = connect_bot(&server, "up");
+        let mut bot = server
+            .accept(&handshake(BotMode::RealTime), TIMEOUT)
+            .unwrap();
+        assert_eq!(bot.mode(), BotMode::RealTime);
+
+        // The game does not wait, so the snake keeps going until the move arrives
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let mut directions = Vec::new();
+        for _ in 0..200 {
+            let direction = bot.next_direction(&simulation.view());
+            directions.push(direction);
+            if direction == Direction::Up {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(directions.last(), Some(&Direction::Up));
+        assert!(directions[..directions.len() - 1]
+            .iter()
+            .all(|&direction| direction == Direction::Right));
+    }
+
+    #[test]
+    fn test_try_accept_without_bot() {
+        let server = BotServer::bind("127.0.0.1:0").unwrap();
+        assert!(server
+            .try_accept(&handshake(BotMode::Lockstep), TIMEOUT)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_stalled_lockstep_bot_times_out() {
+        let server = BotServer::bind("127.0.0.1:0").unwrap();
+        let _client = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+        let mut bot = server
+            .accept(&handshake(BotMode::Lockstep), Duration::from_millis(50))
+            .unwrap();
+
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        let started = Instant::now();
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Right);
+        assert!(started.elapsed() < TIMEOUT);
+        assert!(bot.error().is_none());
+    }
+
+    #[test]
+    fn test_disconnected_bot_keeps_snake_going() {
+        let server = BotServer::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+        let mut bot = server
+            .accept(&handshake(BotMode::Lockstep), TIMEOUT)
+            .unwrap();
+        drop(client);
+
+        let simulation = Simulation::new(BoardConfig::default(), 0);
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Right);
+        assert!(bot.error().is_some());
+        assert_eq!(bot.next_direction(&simulation.view()), Direction::Right);
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use snake::{
+        board::BoardConfig,
+        coordinate::Coordinate,
+        direction::Direction,
+        simulation::DeathCause,
+        snake::Snake,
+        strategy::{GreedyStrategy, Strategy},
+        versus::{VersusOutcome, VersusSimulation, MAX_PLAYERS},
+    };
+
+    fn snake(board: BoardConfig, body: &[(i32, i32)], direction: Direction) -> Snake {
+        let mut snake = Snake::spawn(board, Coordinate(body[0].0, body[0].1), direction);
+        snake.body = body.iter().map(|&(x, y)| Coordinate(x, y)).collect();
+        snake
+    }
+
+    /// Turns both snakes of a fresh game on `board` onto the same row,
+    /// facing each other, and lets them run until the game is over.
+    fn run_into_each_other(board: BoardConfig) -> VersusSimulation {
+        let mut simulation = VersusSimulation::new(board, 2, 0);
+        let mut inputs = [Some(Direction::Down), Some(Direction::Up)];
+        while simulation.step(&inputs) != VersusOutcome::Over {
+            inputs = [Some(Direction::Right), Some(Direction::Left)];
+        }
+        simulation
+    }
+
+    #[test]
+    fn test_snakes_start_apart() {
+        let board = BoardConfig::default();
+        let simulation = VersusSimulation::new(board, MAX_PLAYERS, 0);
+
+        let heads: Vec<Coordinate> = simulation
+            .players()
+            .iter()
+            .map(|player| player.snake().head_position())
+            .collect();
+        for (i, head) in heads.iter().enumerate() {
+            assert!(!board.is_border(*head));
+            assert!(!heads[i + 1..].contains(head));
+        }
+        assert!(!heads.contains(&simulation.food().position));
+    }
+
+    #[test]
+    fn test_heads_meeting_on_same_cell_is_a_draw() {
+        // Both heads reach the middle cell of row 2 on the same tick
+        let simulation = run_into_each_other(BoardConfig::new(9, 5).unwrap());
+
+        assert_eq!(simulation.ticks(), 3);
+        assert_eq!(simulation.winner(), None);
+        for player in simulation.players() {
+            assert_eq!(player.death_cause(), Some(DeathCause::HeadOn));
+        }
+    }
+
+    #[test]
+    fn test_heads_passing_through_each_other_is_a_draw() {
+        // With an even gap the heads would swap cells instead of meeting
+        let simulation = run_into_each_other(BoardConfig::new(10, 5).unwrap());
+
+        assert_eq!(simulation.ticks(), 4);
+        assert_eq!(simulation.winner(), None);
+        for player in simulation.players() {
+            assert_eq!(player.death_cause(), Some(DeathCause::HeadOn));
+        }
+    }
+
+    #[test]
+    fn test_running_into_other_snake_loses() {
+        let board = BoardConfig::default();
+        let snakes = vec![
+            snake(board, &[(10, 5), (10, 6), (10, 7), (10, 8)], Direction::Up),
+            snake(board, &[(11, 7)], Direction::Left),
+        ];
+        let mut simulation = VersusSimulation::with_snakes(board, snakes, 0);
+
+        assert_eq!(simulation.step(&[]), VersusOutcome::Over);
+        assert_eq!(simulation.winner(), Some(0));
+        assert!(simulation.players()[0].is_alive());
+        assert_eq!(
+            simulation.players()[1].death_cause(),
+            Some(DeathCause::OtherSnake)
+        );
+        assert_eq!(simulation.step(&[]), VersusOutcome::Over);
+    }
+
+    #[test]
+    fn test_game_goes_on_while_two_snakes_are_left() {
+        let board = BoardConfig::default();
+        let snakes = vec![
+            snake(board, &[(10, 5), (10, 6), (10, 7), (10, 8)], Direction::Up),
+            snake(board, &[(11, 7)], Direction::Left),
+            snake(board, &[(30, 15)], Direction::Left),
+        ];
+        let mut simulation = VersusSimulation::with_snakes(board, snakes, 0);
+
+        assert_eq!(simulation.step(&[]), VersusOutcome::Died);
+        assert!(!simulation.is_over());
+        assert!(!simulation.players()[1].is_alive());
+
+        // The dead snake is taken off the board and moves no more
+        let dead_head = simulation.players()[1].snake().head_position();
+        simulation.step(&[]);
+        assert_eq!(simulation.players()[1].snake().head_position(), dead_head);
+    }
+
+    #[test]
+    fn test_wall_and_self_collisions_still_apply() {
+        let board = BoardConfig::default();
+        let snakes = vec![
+            snake(board, &[(1, 5)], Direction::Left),
+            snake(
+                board,
+                &[(20, 5), (21, 5), (21, 6), (20, 6), (19, 6)],
+                Direction::Left,
+            ),
+        ];
+        let mut simulation = VersusSimulation::with_snakes(board, snakes, 0);
+
+        simulation.step(&[None, Some(Direction::Down)]);
+        assert_eq!(
+            simulation.players()[0].death_cause(),
+            Some(DeathCause::Wall)
+        );
+        assert_eq!(
+            simulation.players()[1].death_cause(),
+            Some(DeathCause::SelfCollision)
+        );
+        assert_eq!(simulation.winner(), None);
+    }
+
+    #[test]
+    fn test_only_the_snake_that_eats_scores() {
+        let board = BoardConfig::default();
+        // Seed 0 drops the first food in the second snake's circle
+        let mut simulation = VersusSimulation::new(board, 2, 1);
+        let mut strategy = GreedyStrategy;
+        // The second snake circles on the spot while the first goes for the food
+        let circle = [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ];
+
+        for tick in 0.. {
+            let inputs = [
+                Some(strategy.next_direction(&simulation.view(0))),
+                Some(circle[tick % circle.len()]),
+            ];
+
+            if simulation.step(&inputs) == VersusOutcome::Ate {
+                break;
+            }
+            assert!(!simulation.is_over());
+        }
+
+        assert_eq!(simulation.players()[0].score(), 1);
+        assert_eq!(simulation.players()[1].score(), 0);
+        simulation.step(&[]);
+        assert_eq!(simulation.players()[0].snake().body().len(), 2);
+        assert_eq!(simulation.players()[1].snake().body().len(), 1);
+    }
+}